@@ -220,6 +220,212 @@ where
     kth_with(slice, slice.len() / 2, compare)
 }
 
+/// Returns the median of the items yielded by `iter`. returns None if `iter` yields no items
+///
+/// Unlike `median`, when the number of items is even, the lower median is returned.
+/// It is equivalent to collecting the items and taking the kth(items, (items.len() - 1)/2)
+///
+/// # Arguments
+/// * `iter`: iterator over unordered data
+///
+/// # Examples
+/// ```
+/// use rudac::algo::find::median_of;
+///
+/// assert_eq!(median_of(vec![10, 6, 1, 4, 2]), Some(4));
+/// assert_eq!(median_of((1..=10).rev()), Some(5));
+/// assert_eq!(median_of(Vec::<usize>::new()), None);
+/// ```
+pub fn median_of<I, T>(iter: I) -> Option<T>
+where
+    I: IntoIterator<Item = T>,
+    T: Ord,
+{
+    let mut vec: Vec<T> = iter.into_iter().collect();
+
+    if vec.is_empty() {
+        return None;
+    }
+
+    let k = (vec.len() - 1) / 2;
+    let median_index = kth(&mut vec, k);
+    Some(vec.swap_remove(median_index))
+}
+
+/// Returns the kth largest item in the slice. k = 0 returns the largest item
+///
+/// It is equivalent to finding the kth smallest item using the reversed comparison,
+/// so the slice is left untouched and no sorting is involved
+///
+/// # Arguments
+/// * `slice`: slice of unordered data
+/// * `k`: kth
+///
+/// # Panics
+/// * panics if k is out of range: 0 <= k < slice.len()
+///
+/// # Examples
+/// ```
+/// use rudac::algo::find::kth_largest;
+///
+/// let vec = vec![10, 6, 1, 4, 2, 3, 7, 9, 8, 5];
+///
+/// assert_eq!(kth_largest(&vec, 0), 10);
+/// assert_eq!(kth_largest(&vec, 3), 7);
+/// ```
+pub fn kth_largest<T: Ord + Clone>(slice: &[T], k: usize) -> T {
+    if k >= slice.len() {
+        panic!("k is out of range: 0 <= k < slice.len()");
+    }
+
+    let mut vec = slice.to_vec();
+
+    let index = kth_with(&mut vec, k, &|x1: &T, x2: &T| x2.cmp(x1));
+    vec.swap_remove(index)
+}
+
+/// Returns true if `value` is the kth smallest item of the slice, false otherwise.
+///
+/// `value` is the kth smallest item if it is in the slice and exactly k items of the slice are strictly smaller than it.
+/// Items are only counted, so the slice is neither modified nor sorted. Complexity: O(n)
+///
+/// # Arguments
+/// * `slice`: slice of unordered data
+/// * `value`: value to be verified
+/// * `k`: kth
+///
+/// # Examples
+/// ```
+/// use rudac::algo::find::{kth, is_kth};
+///
+/// let mut vec = vec![10, 6, 1, 4, 2, 4, 7, 9, 8, 5];
+///
+/// assert!(is_kth(&vec, &4, 2));
+/// assert!(!is_kth(&vec, &4, 3));
+/// assert!(!is_kth(&vec, &3, 2));
+///
+/// // validate result of a selection
+/// let index = kth(&mut vec, 6);
+/// assert!(is_kth(&vec, &vec[index], 6));
+/// ```
+pub fn is_kth<T: Ord>(slice: &[T], value: &T, k: usize) -> bool {
+    let mut present = false;
+    let mut smaller = 0;
+
+    for item in slice {
+        match item.cmp(value) {
+            Ordering::Less => smaller += 1,
+            Ordering::Equal => present = true,
+            Ordering::Greater => {}
+        }
+    }
+
+    present && smaller == k
+}
+
+/// Finds the kth smallest item of the slice for every k in `ks` and pushes them into `out`, in the order of `ks`.
+///
+/// Once an order statistic is found, the slice is partitioned around it,
+/// so the rest of the order statistics are searched for in the smaller partitions.
+/// It is faster than calling kth for each k separately
+///
+/// # Arguments
+/// * `slice`: slice of unordered data
+/// * `ks`: order statistics to be found
+/// * `out`: vector to push the found items into
+///
+/// # Panics
+/// * panics if any k is out of range: 0 <= k < slice.len()
+///
+/// # Examples
+/// ```
+/// use rudac::algo::find::select_into;
+///
+/// let vec: Vec<usize> = (1..=100).rev().collect();
+/// let mut percentiles = Vec::new();
+///
+/// // 10th, 50th and 90th percentiles
+/// select_into(&vec, &[9, 49, 89], &mut percentiles);
+///
+/// assert_eq!(percentiles, vec![10, 50, 90]);
+/// ```
+pub fn select_into<T: Ord + Clone>(slice: &[T], ks: &[usize], out: &mut Vec<T>) {
+    if ks.iter().any(|k| *k >= slice.len()) {
+        panic!("k is out of range: 0 <= k < slice.len()");
+    }
+
+    let mut vec = slice.to_vec();
+
+    let mut sorted_ks = ks.to_vec();
+    sorted_ks.sort_unstable();
+    sorted_ks.dedup();
+
+    _select_into(&mut vec, &sorted_ks, 0, &|x1: &T, x2: &T| x1.cmp(x2));
+
+    // each requested position now holds its order statistic
+    out.extend(ks.iter().map(|k| vec[*k].clone()));
+}
+
+/// Returns an approximate median of the slice, suitable as a pivot for selection and sorting algorithms.
+///
+/// Items are split into groups of 5 and the exact median of the group medians is returned.
+/// At least 30% of the items are smaller than or equal to the returned item and at least 30% are greater than or equal to it,
+/// so partitioning around it never leaves more than about 70% of the items on one side
+///
+/// # Arguments
+/// * `slice`: slice of unordered data
+///
+/// # Panics
+/// * panics if slice is empty
+///
+/// # Examples
+/// ```
+/// use rudac::algo::find::median_of_medians;
+///
+/// let vec: Vec<usize> = (0..100).rev().collect();
+///
+/// let pivot = median_of_medians(&vec);
+/// assert!(30 <= pivot && pivot < 70);
+/// ```
+pub fn median_of_medians<T: Ord + Clone>(slice: &[T]) -> T {
+    if slice.is_empty() {
+        panic!("Slice must not be empty");
+    }
+
+    let medians = slice.chunks(5).map(|chunk| {
+        let mut group = chunk.to_vec();
+        set_median_with(&mut group, &|x1: &T, x2: &T| x1.cmp(x2));
+
+        group.swap_remove(0)
+    });
+
+    median_of(medians).unwrap()
+}
+
+// moves the kth smallest item of `slice` to index k for every k in `ks`.
+// `ks` must be sorted and are relative to the start of the original slice, which is `offset` items before `slice`
+fn _select_into<T, F>(slice: &mut [T], ks: &[usize], offset: usize, compare: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    if ks.is_empty() {
+        return;
+    }
+
+    // start from the middle order statistic so both partitions get half of the remaining ones
+    let mid = ks.len() / 2;
+    let index = kth_with(slice, ks[mid] - offset, compare);
+
+    let (lower, higher) = slice.split_at_mut(index);
+    _select_into(lower, &ks[..mid], offset, compare);
+    _select_into(
+        &mut higher[1..],
+        &ks[mid + 1..],
+        offset + index + 1,
+        compare,
+    );
+}
+
 fn set_median_with<T, F>(slice: &mut [T], compare: &F)
 where
     F: Fn(&T, &T) -> Ordering,
@@ -310,4 +516,137 @@ mod tests {
         let index = kth(&mut vec, 5);
         assert_eq!(vec[index], 5);
     }
+
+    #[test]
+    fn algo_find_median_of_1() {
+        assert_eq!(median_of(vec![3, 1, 2]), Some(2));
+        assert_eq!(median_of(vec![5, 1, 4, 2, 3, 3, 9]), Some(3));
+        assert_eq!(median_of(Some(7)), Some(7));
+    }
+
+    #[test]
+    fn algo_find_median_of_2() {
+        assert_eq!(median_of(vec![4, 1, 3, 2]), Some(2));
+        assert_eq!(median_of((0..100).rev()), Some(49));
+        assert_eq!(median_of(vec![2, 2]), Some(2));
+    }
+
+    #[test]
+    fn algo_find_median_of_3() {
+        assert_eq!(median_of(Vec::<usize>::new()), None);
+        assert_eq!(median_of(std::iter::empty::<usize>()), None);
+    }
+
+    #[test]
+    fn algo_find_kth_largest_1() {
+        let vec: Vec<usize> = (0..100).map(|i| (i * 37) % 23).collect();
+
+        let mut sorted = vec.clone();
+        sorted.sort_by(|x1, x2| x2.cmp(x1));
+
+        for (k, expected) in sorted.iter().enumerate() {
+            assert_eq!(kth_largest(&vec, k), *expected);
+        }
+
+        assert_eq!(kth_largest(&vec, 0), *vec.iter().max().unwrap());
+        assert_eq!(kth_largest(&vec, vec.len() - 1), *vec.iter().min().unwrap());
+        assert_eq!(kth_largest(&[7], 0), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "k is out of range: 0 <= k < slice.len()")]
+    fn algo_find_kth_largest_panic() {
+        kth_largest(&[1, 2, 3], 3);
+    }
+
+    #[test]
+    fn algo_find_select_into_1() {
+        let vec: Vec<usize> = (0..1000).map(|i| (i * 37) % 101).collect();
+
+        let mut sorted = vec.clone();
+        sorted.sort();
+
+        let mut out = vec![7];
+        select_into(&vec, &[899, 99, 499], &mut out);
+        assert_eq!(out, vec![7, sorted[899], sorted[99], sorted[499]]);
+
+        let ks: Vec<usize> = (0..vec.len()).rev().step_by(3).collect();
+        let mut out = Vec::new();
+        select_into(&vec, &ks, &mut out);
+        for (k, item) in ks.iter().zip(out) {
+            assert_eq!(item, sorted[*k]);
+        }
+
+        let mut out = Vec::new();
+        select_into(&vec, &[5, 5, 0, 999], &mut out);
+        assert_eq!(out, vec![sorted[5], sorted[5], sorted[0], sorted[999]]);
+
+        let mut out: Vec<usize> = Vec::new();
+        select_into(&[], &[], &mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "k is out of range: 0 <= k < slice.len()")]
+    fn algo_find_select_into_panic() {
+        select_into(&[1, 2, 3], &[0, 3], &mut Vec::new());
+    }
+
+    #[test]
+    fn algo_find_median_of_medians_1() {
+        let inputs: Vec<Vec<usize>> = vec![
+            (0..1000).collect(),
+            (0..1000).rev().collect(),
+            (0..1000).map(|i| (i * 37) % 1009).collect(),
+            (0..1000).map(|i| i % 7).collect(),
+            (0..1000).map(|i| if i % 5 < 3 { i } else { 0 }).collect(),
+            vec![3; 1000],
+        ];
+
+        for input in inputs {
+            for size in (50..=1000).step_by(50) {
+                let slice = &input[..size];
+                let pivot = median_of_medians(slice);
+
+                // pivot lies between the 30th and 70th percentiles
+                let not_greater = slice.iter().filter(|item| **item <= pivot).count();
+                let not_smaller = slice.iter().filter(|item| **item >= pivot).count();
+                assert!(not_greater * 10 >= size * 3);
+                assert!(not_smaller * 10 >= size * 3);
+            }
+        }
+
+        assert_eq!(median_of_medians(&[7]), 7);
+        assert_eq!(median_of_medians(&[2, 9, 4]), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slice must not be empty")]
+    fn algo_find_median_of_medians_panic() {
+        median_of_medians::<usize>(&[]);
+    }
+
+    #[test]
+    fn algo_find_is_kth_1() {
+        let vec = vec![10, 6, 1, 3, 2, 7, 9, 8, 5, 4];
+
+        for k in 0..vec.len() {
+            assert!(is_kth(&vec, &(k + 1), k));
+            assert!(!is_kth(&vec, &(k + 1), k + 1));
+        }
+        assert!(!is_kth(&vec, &0, 0));
+        assert!(!is_kth(&vec, &11, 10));
+    }
+
+    #[test]
+    fn algo_find_is_kth_duplicates() {
+        let vec = vec![3, 1, 2, 3, 1, 3];
+
+        assert!(is_kth(&vec, &1, 0));
+        assert!(!is_kth(&vec, &1, 1));
+        assert!(is_kth(&vec, &2, 2));
+        assert!(is_kth(&vec, &3, 3));
+        assert!(!is_kth(&vec, &3, 4));
+        assert!(!is_kth(&vec, &3, 5));
+    }
 }
@@ -1,7 +1,13 @@
 mod kth;
 
+pub use crate::algo::transform::partition;
+pub use crate::algo::transform::partition_with;
+
 pub use kth::kth;
 pub use kth::kth_with;
+pub use kth::kth_largest;
+pub use kth::select_into;
+pub use kth::is_kth;
 
 pub use kth::min;
 pub use kth::min_with;
@@ -10,4 +16,6 @@ pub use kth::max;
 pub use kth::max_with;
 
 pub use kth::median;
-pub use kth::median_with;
\ No newline at end of file
+pub use kth::median_with;
+pub use kth::median_of;
+pub use kth::median_of_medians;
\ No newline at end of file
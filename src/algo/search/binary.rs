@@ -84,6 +84,170 @@ where
     None
 }
 
+/// Binary search that also counts the comparisons it performs, useful for comparing search strategies on real data.
+///
+/// Returns index of the found item or None, together with the number of comparisons between `item` and items of the slice
+///
+/// # Arguments
+/// * `slice`: slice of ordered data
+/// * `item`: item to be searched for
+///
+/// # Examples
+/// ```
+/// use rudac::algo::search::binary_search_instrumented;
+///
+/// let vec = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// // 6 is in the middle of the slice
+/// assert_eq!(binary_search_instrumented(&vec, &6), (Some(5), 1));
+///
+/// let (index, comparisons) = binary_search_instrumented(&vec, &11);
+/// assert_eq!(index, None);
+/// assert!(comparisons <= 5);
+/// ```
+pub fn binary_search_instrumented<T: Ord>(slice: &[T], item: &T) -> (Option<usize>, usize) {
+    let mut comparisons = 0;
+
+    // item can only be in slice[left..right]
+    let mut left = 0;
+    let mut right = slice.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        comparisons += 1;
+        match slice[mid].cmp(item) {
+            Ordering::Equal => return (Some(mid), comparisons),
+            Ordering::Less => left = mid + 1,
+            Ordering::Greater => right = mid,
+        }
+    }
+
+    (None, comparisons)
+}
+
+/// Returns the half-open range of indices of all items equal to `item` in a sorted slice, or None if there is no such item.
+/// Range is found by two binary searches for its leftmost and rightmost bounds, so it takes O(log(n)) regardless of number of duplicates
+///
+/// # Arguments
+/// * `slice`: slice of ordered data
+/// * `item`: item to be searched for
+///
+/// # Examples
+/// ```
+/// use rudac::algo::search::binary_search_range;
+///
+/// let vec = vec![1, 2, 2, 2, 3];
+///
+/// assert_eq!(binary_search_range(&vec, &2), Some(1..4));
+/// assert_eq!(binary_search_range(&vec, &3), Some(4..5));
+/// assert_eq!(binary_search_range(&vec, &4), None);
+/// ```
+pub fn binary_search_range<T: Ord>(slice: &[T], item: &T) -> Option<std::ops::Range<usize>> {
+    let start = partition_point(slice.len(), |i| slice[i] < *item);
+    let end = start + partition_point(slice.len() - start, |i| slice[start + i] <= *item);
+
+    if start == end {
+        None
+    } else {
+        Some(start..end)
+    }
+}
+
+/// Returns index of the first item strictly greater than `item` in a sorted slice, or None if there is no such item.
+/// It is the slice counterpart of the successor of `item` in a search tree
+///
+/// # Arguments
+/// * `slice`: slice of ordered data
+/// * `item`: item to be compared against
+///
+/// # Examples
+/// ```
+/// use rudac::algo::search::first_greater;
+///
+/// let vec = vec![10, 20, 30, 40];
+///
+/// assert_eq!(first_greater(&vec, &20), Some(2));
+/// assert_eq!(first_greater(&vec, &25), Some(2));
+/// assert_eq!(first_greater(&vec, &40), None);
+/// ```
+pub fn first_greater<T: Ord>(slice: &[T], item: &T) -> Option<usize> {
+    let index = partition_point(slice.len(), |i| slice[i] <= *item);
+
+    if index == slice.len() {
+        None
+    } else {
+        Some(index)
+    }
+}
+
+/// Returns index of the last item strictly less than `item` in a sorted slice, or None if there is no such item.
+/// It is the slice counterpart of the predecessor of `item` in a search tree
+///
+/// # Arguments
+/// * `slice`: slice of ordered data
+/// * `item`: item to be compared against
+///
+/// # Examples
+/// ```
+/// use rudac::algo::search::last_less;
+///
+/// let vec = vec![10, 20, 30, 40];
+///
+/// assert_eq!(last_less(&vec, &20), Some(0));
+/// assert_eq!(last_less(&vec, &25), Some(1));
+/// assert_eq!(last_less(&vec, &10), None);
+/// ```
+pub fn last_less<T: Ord>(slice: &[T], item: &T) -> Option<usize> {
+    let index = partition_point(slice.len(), |i| slice[i] < *item);
+
+    if index == 0 {
+        None
+    } else {
+        Some(index - 1)
+    }
+}
+
+/// Binary searches the index where a monotonic predicate flips from true to false.
+///
+/// Returns the first index in `0..len` for which `pred` returns false. `pred` must return true for all indices before it and false for all indices after it.
+/// If `pred` is true for every index, `len` is returned and if it is false for every index, 0 is returned
+///
+/// # Arguments
+/// * `len`: number of indices to be searched
+/// * `pred`: monotonic predicate over indices
+///
+/// # Examples
+/// ```
+/// use rudac::algo::search::partition_point;
+///
+/// // smallest number whose square is at least 50
+/// assert_eq!(partition_point(100, |i| i * i < 50), 8);
+///
+/// assert_eq!(partition_point(10, |_| true), 10);
+/// assert_eq!(partition_point(10, |_| false), 0);
+/// ```
+pub fn partition_point<F>(len: usize, pred: F) -> usize
+where
+    F: Fn(usize) -> bool,
+{
+    // pred is true for all indices before `left` and false for all indices from `right` onward
+    let mut left = 0;
+    let mut right = len;
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        if pred(mid) {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+
+    left
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +271,95 @@ mod tests {
             assert_eq!(binary_search(&vec, &i), None);
         }
     }
+
+    #[test]
+    fn algo_search_partition_point() {
+        for boundary in 0..=100 {
+            assert_eq!(partition_point(100, |i| i < boundary), boundary);
+        }
+
+        assert_eq!(partition_point(0, |_| true), 0);
+        assert_eq!(partition_point(50, |_| true), 50);
+        assert_eq!(partition_point(50, |_| false), 0);
+        assert_eq!(
+            partition_point(usize::MAX, |i| i < usize::MAX - 1),
+            usize::MAX - 1
+        );
+
+        let sorted = [1, 3, 3, 5, 8, 13];
+        assert_eq!(partition_point(sorted.len(), |i| sorted[i] < 3), 1);
+        assert_eq!(partition_point(sorted.len(), |i| sorted[i] <= 3), 3);
+    }
+
+    #[test]
+    fn algo_search_binary_search_instrumented() {
+        for n in 1..300 {
+            let vec: Vec<usize> = (0..n).map(|i| i * 2 + 1).collect();
+            let bound = (n as f64).log2().ceil() as usize + 1;
+
+            for i in 0..n {
+                let (index, comparisons) = binary_search_instrumented(&vec, &(i * 2 + 1));
+                assert_eq!(index, Some(i));
+                assert!(comparisons <= bound);
+            }
+
+            // misses before, between and after the items of the slice
+            for i in 0..=n {
+                let (index, comparisons) = binary_search_instrumented(&vec, &(i * 2));
+                assert_eq!(index, None);
+                assert!(comparisons <= bound);
+            }
+        }
+
+        assert_eq!(binary_search_instrumented(&[] as &[usize], &1), (None, 0));
+    }
+
+    #[test]
+    fn algo_search_binary_search_range() {
+        let vec = [1, 2, 2, 2, 3];
+        assert_eq!(binary_search_range(&vec, &2), Some(1..4));
+        assert_eq!(binary_search_range(&vec, &1), Some(0..1));
+        assert_eq!(binary_search_range(&vec, &0), None);
+        assert_eq!(binary_search_range(&vec, &4), None);
+
+        let vec: Vec<usize> = (0..100).map(|i| i / 7 * 2).collect();
+        for item in 0..30 {
+            let expected: Vec<usize> = (0..vec.len()).filter(|i| vec[*i] == item).collect();
+
+            match binary_search_range(&vec, &item) {
+                Some(range) => assert!(range.eq(expected)),
+                None => assert!(expected.is_empty()),
+            }
+        }
+
+        assert_eq!(binary_search_range(&[] as &[usize], &1), None);
+    }
+
+    #[test]
+    fn algo_search_first_greater_last_less() {
+        let vec = vec![10, 20, 30, 40];
+
+        assert_eq!(first_greater(&vec, &0), Some(0));
+        assert_eq!(first_greater(&vec, &10), Some(1));
+        assert_eq!(first_greater(&vec, &15), Some(1));
+        assert_eq!(first_greater(&vec, &30), Some(3));
+        assert_eq!(first_greater(&vec, &40), None);
+        assert_eq!(first_greater(&vec, &50), None);
+
+        assert_eq!(last_less(&vec, &0), None);
+        assert_eq!(last_less(&vec, &10), None);
+        assert_eq!(last_less(&vec, &15), Some(0));
+        assert_eq!(last_less(&vec, &30), Some(1));
+        assert_eq!(last_less(&vec, &40), Some(2));
+        assert_eq!(last_less(&vec, &50), Some(3));
+
+        // duplicates are skipped entirely
+        let vec = vec![1, 2, 2, 2, 3];
+        assert_eq!(first_greater(&vec, &2), Some(4));
+        assert_eq!(last_less(&vec, &2), Some(0));
+
+        let empty: Vec<usize> = vec![];
+        assert_eq!(first_greater(&empty, &0), None);
+        assert_eq!(last_less(&empty, &0), None);
+    }
 }
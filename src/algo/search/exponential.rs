@@ -1,4 +1,4 @@
-use crate::algo::search::binary_search_with;
+use crate::algo::search::partition_point;
 use std::cmp::{Ord, Ordering};
 
 /// Exponential search is a search algorithm that finds the position of a target value within a sorted array.
@@ -55,32 +55,115 @@ pub fn exponential_search<T: Ord>(slice: &[T], item: &T) -> Option<usize> {
 pub fn exponential_search_with<T, F>(slice: &[T], item: &T, compare: &F) -> Option<usize>
 where
     F: Fn(&T, &T) -> Ordering,
+{
+    _exponential_search(slice, &|x: &T| compare(x, item))
+}
+
+/// Exponential search is a search algorithm that finds the position of a target value within a sorted array.
+/// Returns index of the item whose key is equal to `key`, None otherwise
+///
+/// # Arguments
+/// * `slice`: slice of data ordered by the key extracted using `f`
+/// * `key`: key to be searched for
+/// * `f`: closure that extracts the key of an item
+///
+/// # Examples
+/// ```
+/// use rudac::algo::search::exponential_search_by_key;
+///
+/// // consider a vector of 2d points sorted by their y axis
+/// let vec = vec![(3,1), (4,2), (5,3), (3,4), (10,5), (2,6), (6,7), (9,8), (8,9), (1,10)];
+///
+/// assert_eq!(exponential_search_by_key(&vec, &4, |point| point.1), Some(3));
+/// assert_eq!(exponential_search_by_key(&vec, &11, |point| point.1), None);
+/// ```
+pub fn exponential_search_by_key<T, B, F>(slice: &[T], key: &B, f: F) -> Option<usize>
+where
+    B: Ord,
+    F: Fn(&T) -> B,
+{
+    _exponential_search(slice, &|x: &T| f(x).cmp(key))
+}
+
+// `compare` returns the ordering of an item relative to the target
+fn _exponential_search<T, F>(slice: &[T], compare: &F) -> Option<usize>
+where
+    F: Fn(&T) -> Ordering,
 {
     let n = slice.len();
+    if n == 0 {
+        return None;
+    }
 
-    if compare(&slice[0], item) == Ordering::Equal {
+    if compare(&slice[0]) == Ordering::Equal {
         return Some(0);
     }
 
     let mut i = 1;
-    while i < n
-        && (compare(&slice[i], item) == Ordering::Less
-            || compare(&slice[i], item) == Ordering::Equal)
-    {
+    while i < n && compare(&slice[i]) != Ordering::Greater {
         i *= 2;
     }
 
-    let upper = std::cmp::min(i + 1, n);
-    let index = binary_search_with(&slice[i / 2..upper], item, compare);
+    // target can only be in the bracket between the last two probes
+    let lower = i / 2;
+    let bracket = &slice[lower..std::cmp::min(i + 1, n)];
+    let index = lower + partition_point(bracket.len(), |j| compare(&bracket[j]) == Ordering::Less);
 
-    if index.is_some() {
-        return Some(index.unwrap() + i / 2);
-    }
-    else {
-        return None
+    if index < n && compare(&slice[index]) == Ordering::Equal {
+        Some(index)
+    } else {
+        None
     }
 }
 
+/// Galloping search finds the position of a target value within a sorted sequence whose length is unknown or expensive to compute.
+/// Starting at index 0, the probe is doubled until the target is at or before it, then the bracket is binary searched.
+/// Returns index of the found item, None otherwise
+///
+/// # Arguments
+/// * `f`: closure that compares the item at the given index with the target
+///
+/// # Examples
+/// ```
+/// use rudac::algo::search::gallop;
+///
+/// // squares of natural numbers, generated lazily
+/// assert_eq!(gallop(|i| (i * i).cmp(&144)), Some(12));
+/// assert_eq!(gallop(|i| (i * i).cmp(&0)), Some(0));
+///
+/// assert_eq!(gallop(|i| (i * i).cmp(&50)), None);
+/// ```
+pub fn gallop<F>(f: F) -> Option<usize>
+where
+    F: Fn(usize) -> Ordering,
+{
+    // target is after every index before `low` and at or before `bound`
+    let mut low = 0;
+    let mut bound = 0;
+
+    loop {
+        match f(bound) {
+            Ordering::Equal => return Some(bound),
+            Ordering::Greater => break,
+            Ordering::Less => {
+                if bound == usize::MAX {
+                    return None;
+                }
+
+                low = bound + 1;
+                bound = std::cmp::max(1, bound.saturating_mul(2));
+            }
+        }
+    }
+
+    let index = low + partition_point(bound - low, |i| f(low + i) == Ordering::Less);
+
+    if index < bound && f(index) == Ordering::Equal {
+        Some(index)
+    } else {
+        None
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -106,4 +189,38 @@ mod tests {
             assert_eq!(exponential_search(&vec, &i), None);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn algo_search_gallop() {
+        // odd numbers, without knowing where the sequence ends
+        let odd = |i: usize| 2 * i + 1;
+
+        for i in 0..1000 {
+            assert_eq!(gallop(|index| odd(index).cmp(&odd(i))), Some(i));
+            assert_eq!(gallop(|index| odd(index).cmp(&(2 * i))), None);
+        }
+
+        assert_eq!(gallop(|index| index.cmp(&usize::MAX)), Some(usize::MAX));
+        assert_eq!(gallop(|_| Ordering::Less), None);
+        assert_eq!(gallop(|_| Ordering::Greater), None);
+    }
+
+    #[test]
+    fn algo_search_exponential_search_by_key() {
+        // (x, y) points sorted by y, where y is twice the index
+        let points: Vec<(usize, usize)> = (0..70).map(|i| (70 - i, 2 * i)).collect();
+
+        // slice lengths on both sides of each power of two end the doubling probes in every possible bracket
+        for len in 0..=points.len() {
+            let slice = &points[..len];
+
+            let search = |y: usize| exponential_search_by_key(slice, &y, |point| point.1);
+
+            for i in 0..len {
+                assert_eq!(search(2 * i), Some(i));
+                assert_eq!(search(2 * i + 1), None);
+            }
+            assert_eq!(search(2 * len), None);
+        }
+    }
+}
@@ -58,8 +58,46 @@ pub fn fibonacci_search<T: Ord>(slice: &[T], item: &T) -> Option<usize> {
 pub fn fibonacci_search_with<T, F>(slice: &[T], item: &T, compare: &F) -> Option<usize>
 where
     F: Fn(&T, &T) -> Ordering,
+{
+    _fibonacci_search(slice, &|x: &T| compare(x, item))
+}
+
+/// Fibonacci search is a search algorithm that finds the position of a target value within a sorted array.
+/// Returns index of the item whose key is equal to `key`, None otherwise
+///
+/// # Arguments
+/// * `slice`: slice of data ordered by the key extracted using `f`
+/// * `key`: key to be searched for
+/// * `f`: closure that extracts the key of an item
+///
+/// # Examples
+/// ```
+/// use rudac::algo::search::fibonacci_search_by_key;
+///
+/// // consider a vector of 2d points sorted by their y axis
+/// let vec = vec![(3,1), (4,2), (5,3), (3,4), (10,5), (2,6), (6,7), (9,8), (8,9), (1,10)];
+///
+/// assert_eq!(fibonacci_search_by_key(&vec, &4, |point| point.1), Some(3));
+/// assert_eq!(fibonacci_search_by_key(&vec, &11, |point| point.1), None);
+/// ```
+pub fn fibonacci_search_by_key<T, B, F>(slice: &[T], key: &B, f: F) -> Option<usize>
+where
+    B: Ord,
+    F: Fn(&T) -> B,
+{
+    _fibonacci_search(slice, &|x: &T| f(x).cmp(key))
+}
+
+// `compare` returns the ordering of an item relative to the target
+fn _fibonacci_search<T, F>(slice: &[T], compare: &F) -> Option<usize>
+where
+    F: Fn(&T) -> Ordering,
 {
     let n = slice.len();
+    if n == 0 {
+        return None;
+    }
+
     let mut fib2 = 0;
     let mut fib1 = 1;
     let mut fib = fib2 + fib1;
@@ -75,12 +113,12 @@ where
     while fib > 1 {
         let i = std::cmp::min(offset + (fib2 as i64), (n - 1) as i64) as usize;
 
-        if compare(&slice[i], item) == Ordering::Less {
+        if compare(&slice[i]) == Ordering::Less {
             fib = fib1;
             fib1 = fib2;
             fib2 = fib - fib1;
             offset = i as i64;
-        } else if compare(&slice[i], item) == Ordering::Greater {
+        } else if compare(&slice[i]) == Ordering::Greater {
             fib = fib2;
             fib1 = fib1 - fib2;
             fib2 = fib - fib1;
@@ -90,7 +128,7 @@ where
     }
 
     let upper = std::cmp::min((offset + 1) as usize, n - 1);
-    if compare(&slice[upper], item) == Ordering::Equal {
+    if compare(&slice[upper]) == Ordering::Equal {
         return Some(upper);
     }
 
@@ -120,4 +158,31 @@ mod tests {
             assert_eq!(fibonacci_search(&vec, &i), None);
         }
     }
+
+    struct Employee {
+        id: usize,
+        name: String,
+    }
+
+    #[test]
+    fn algo_search_fibonacci_search_by_key() {
+        let employees: Vec<Employee> = (0..100)
+            .map(|i| Employee {
+                id: i * 3,
+                name: format!("employee{}", i),
+            })
+            .collect();
+
+        for id in 0..310 {
+            let expected = employees.iter().position(|employee| employee.id == id);
+            let found = fibonacci_search_by_key(&employees, &id, |employee| employee.id);
+
+            assert_eq!(found, expected);
+            if let Some(index) = found {
+                assert_eq!(employees[index].name, format!("employee{}", id / 3));
+            }
+        }
+
+        assert_eq!(fibonacci_search_by_key(&employees[..0], &0, |employee| employee.id), None);
+    }
 }
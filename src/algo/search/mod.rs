@@ -6,6 +6,11 @@ mod fibonacci;
 
 pub use binary::binary_search;
 pub use binary::binary_search_with;
+pub use binary::binary_search_instrumented;
+pub use binary::binary_search_range;
+pub use binary::first_greater;
+pub use binary::last_less;
+pub use binary::partition_point;
 
 pub use linear::linear_search;
 pub use linear::linear_search_with;
@@ -13,7 +18,10 @@ pub use linear::linear_search_with;
 
 pub use exponential::exponential_search;
 pub use exponential::exponential_search_with;
+pub use exponential::exponential_search_by_key;
+pub use exponential::gallop;
 
 
 pub use fibonacci::fibonacci_search;
-pub use fibonacci::fibonacci_search_with;
\ No newline at end of file
+pub use fibonacci::fibonacci_search_with;
+pub use fibonacci::fibonacci_search_by_key;
\ No newline at end of file
@@ -0,0 +1,78 @@
+/// Counting sort is an integer sorting algorithm that counts the occurrences of each value instead of comparing items.
+/// It runs in O(n + max) time, which is faster than comparison-based sorts when `max` is small compared to number of items.
+///
+/// It allocates a counting array of `max + 1` elements, so memory usage grows with `max` regardless of number of items.
+/// Prefer a comparison-based sort when values are spread over a large range
+///
+/// # Arguments
+/// * `slice`: slice of data to be sorted
+/// * `max`: largest value that may appear in the slice
+///
+/// # Panics
+/// * panics if an item of the slice is greater than `max`
+///
+/// # Examples
+/// ```
+/// use rudac::algo::sort::counting_sort;
+///
+/// let mut vec = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// counting_sort(&mut vec, 9);
+///
+/// assert_eq!(vec, vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9]);
+/// ```
+pub fn counting_sort(slice: &mut [u32], max: u32) {
+    let mut counts = vec![0_usize; max as usize + 1];
+
+    for item in slice.iter() {
+        if *item > max {
+            panic!("Item is greater than max");
+        }
+
+        counts[*item as usize] += 1;
+    }
+
+    // write each value back as many times as it was counted
+    let mut index = 0;
+    for (value, count) in counts.into_iter().enumerate() {
+        for item in &mut slice[index..index + count] {
+            *item = value as u32;
+        }
+        index += count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::sort::test_util;
+
+    #[test]
+    fn algo_sort_counting_1() {
+        let inputs: Vec<Vec<u32>> = test_util::inputs()
+            .into_iter()
+            .map(|input| input.into_iter().map(|item| item as u32).collect())
+            .collect();
+
+        test_util::assert_sorts(inputs, |slice| counting_sort(slice, 100));
+    }
+
+    #[test]
+    fn algo_sort_counting_2() {
+        let mut vec = vec![0, 5, 0, 5];
+        counting_sort(&mut vec, 5);
+        assert_eq!(vec, vec![0, 0, 5, 5]);
+
+        let mut vec = vec![0, 0, 0];
+        counting_sort(&mut vec, 0);
+        assert_eq!(vec, vec![0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Item is greater than max")]
+    fn algo_sort_counting_panic() {
+        let mut vec = vec![1, 2, 11, 3];
+
+        counting_sort(&mut vec, 10);
+    }
+}
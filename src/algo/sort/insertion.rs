@@ -0,0 +1,71 @@
+use crate::algo::search::partition_point;
+use std::cmp::{Ord, Ordering};
+
+/// Insertion sort builds the sorted slice one item at a time by inserting each item into the sorted prefix before it.
+/// Insertion point is found by a binary search, so it takes O(n log(n)) comparisons but O(n^2) moves in the worst case.
+/// It is stable and runs in near-linear time on nearly sorted data
+///
+/// # Arguments
+/// * `slice`: slice of data to be sorted
+///
+/// # Examples
+/// ```
+/// use rudac::algo::sort::insertion_sort;
+///
+/// let mut vec = vec![10, 6, 1, 4, 2, 3, 7, 9, 8, 5];
+///
+/// insertion_sort(&mut vec);
+///
+/// assert_eq!(vec, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+/// ```
+pub fn insertion_sort<T: Ord>(slice: &mut [T]) {
+    insertion_sort_with(slice, &|x1: &T, x2: &T| x1.cmp(x2))
+}
+
+/// Insertion sort builds the sorted slice one item at a time by inserting each item into the sorted prefix before it.
+/// It is stable: equal items keep their relative order
+///
+/// # Arguments
+/// * `slice`: slice of data to be sorted
+/// * `compare`: custom comparison closure
+///
+/// # Examples
+/// ```
+/// use rudac::algo::sort::insertion_sort_with;
+///
+/// // consider vector of 2d points
+/// let mut vec = vec![(1, 10), (2,6), (3,1), (3,4), (4,2), (5,3), (6,7), (8,9), (9,8), (10,5)];
+///
+/// // sort based on y axis
+/// insertion_sort_with(&mut vec, &|x1,x2| {x1.1.cmp(&x2.1)});
+///
+/// assert_eq!(vec, vec![(3,1), (4,2), (5,3), (3,4), (10,5), (2,6), (6,7), (9,8), (8,9), (1,10)]);
+/// ```
+pub fn insertion_sort_with<T, F>(slice: &mut [T], compare: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    for i in 1..slice.len() {
+        // insert after the equal items of the sorted prefix to keep the sort stable
+        let (sorted, rest) = slice.split_at(i);
+        let index = partition_point(i, |j| compare(&sorted[j], &rest[0]) != Ordering::Greater);
+
+        slice[index..=i].rotate_right(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::sort::test_util;
+
+    #[test]
+    fn algo_sort_insertion_1() {
+        test_util::assert_sorts(test_util::inputs(), insertion_sort);
+    }
+
+    #[test]
+    fn algo_sort_insertion_stable() {
+        test_util::assert_stable(|slice| insertion_sort_with(slice, &|x1, x2| x1.0.cmp(&x2.0)));
+    }
+}
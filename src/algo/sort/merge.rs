@@ -1,6 +1,7 @@
 use std::cmp::{Ord, Ordering};
 
-/// Merge sort is an efficient, general-purpose, comparison-based sorting algorithm
+/// Merge sort is an efficient, general-purpose, comparison-based sorting algorithm.
+/// It is stable: equal items keep their relative order
 /// 
 /// # Arguments
 /// * `slice`: slice of data to be sorted
@@ -15,11 +16,12 @@ use std::cmp::{Ord, Ordering};
 /// 
 /// assert_eq!(vec, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
 /// ```
-pub fn merge_sort<T: Copy + Ord>(slice: &mut [T]) {
+pub fn merge_sort<T: Clone + Ord>(slice: &mut [T]) {
     merge_sort_with(slice, &|left: &T, right: &T| left.cmp(&right))
 }
 
-/// Merge sort is an efficient, general-purpose, comparison-based sorting algorithm
+/// Merge sort is an efficient, general-purpose, comparison-based sorting algorithm.
+/// It is stable: equal items keep their relative order
 /// 
 /// # Arguments
 /// * `slice`: slice of data to be sorted
@@ -37,7 +39,7 @@ pub fn merge_sort<T: Copy + Ord>(slice: &mut [T]) {
 /// 
 /// assert_eq!(vec, vec![(3,1), (4,2), (5,3), (3,4), (10,5), (2,6), (6,7), (9,8), (8,9), (1,10)]);
 /// ```
-pub fn merge_sort_with<T: Copy, F>(slice: &mut [T], compare: &F)
+pub fn merge_sort_with<T: Clone, F>(slice: &mut [T], compare: &F)
 where
     F: Fn(&T, &T) -> Ordering,
 {
@@ -55,10 +57,10 @@ where
 
     merge_with(&slice[0..middle], &slice[middle..slice_len], &mut merge[..], compare);
 
-    slice.copy_from_slice(&merge);
+    slice.clone_from_slice(&merge);
 }
 
-fn merge_with<T: Copy, F>(left: &[T], right: &[T], merge: &mut [T], compare: F)
+fn merge_with<T: Clone, F>(left: &[T], right: &[T], merge: &mut [T], compare: F)
 where
     F: Fn(&T, &T) -> Ordering,
 {
@@ -67,27 +69,29 @@ where
     let mut right_index = 0;
     let mut merged_index = 0;
     while left_index < left.len() && right_index < right.len() {
-        if compare(&left[left_index], &right[right_index]) == Ordering::Less {
-            merge[merged_index] = left[left_index];
+        // on equal items, take the left one first to keep the sort stable
+        if compare(&left[left_index], &right[right_index]) != Ordering::Greater {
+            merge[merged_index] = left[left_index].clone();
             merged_index += 1;
             left_index += 1;
         } else {
-            merge[merged_index] = right[right_index];
+            merge[merged_index] = right[right_index].clone();
             merged_index += 1;
             right_index += 1;
         }
     }
     if left_index < left.len() {
-        merge[merged_index..].copy_from_slice(&left[left_index..]);
+        merge[merged_index..].clone_from_slice(&left[left_index..]);
     }
     if right_index < right.len() {
-        merge[merged_index..].copy_from_slice(&right[right_index..]);
+        merge[merged_index..].clone_from_slice(&right[right_index..]);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algo::sort::test_util;
 
     #[test]
     fn algo_sort_merge_1() {
@@ -103,4 +107,27 @@ mod tests {
             assert_eq!(vec[left_index], left_index);
         }
     }
+
+    #[test]
+    fn algo_sort_merge_2() {
+        test_util::assert_sorts(test_util::inputs(), merge_sort);
+    }
+
+    #[test]
+    fn algo_sort_merge_stable() {
+        test_util::assert_stable(|slice| merge_sort_with(slice, &|x1, x2| x1.0.cmp(&x2.0)));
+    }
+
+    #[test]
+    fn algo_sort_merge_clone() {
+        let mut vec = vec![
+            String::from("rudac"),
+            String::from("is"),
+            String::from("awesome"),
+        ];
+
+        merge_sort(&mut vec);
+
+        assert_eq!(vec, vec!["awesome", "is", "rudac"]);
+    }
 }
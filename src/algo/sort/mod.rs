@@ -1,7 +1,15 @@
 mod quick;
 mod merge;
+mod counting;
+mod insertion;
+
+#[cfg(test)]
+mod test_util;
 
 pub use quick::quick_sort;
 pub use quick::quick_sort_with;
 pub use merge::merge_sort;
-pub use merge::merge_sort_with;
\ No newline at end of file
+pub use merge::merge_sort_with;
+pub use counting::counting_sort;
+pub use insertion::insertion_sort;
+pub use insertion::insertion_sort_with;
\ No newline at end of file
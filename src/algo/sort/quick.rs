@@ -63,6 +63,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algo::sort::test_util;
 
     #[test]
     fn algo_sort_quick_1() {
@@ -78,4 +79,9 @@ mod tests {
             assert_eq!(vec[i], i);
         }
     }
+
+    #[test]
+    fn algo_sort_quick_2() {
+        test_util::assert_sorts(test_util::inputs(), quick_sort);
+    }
 }
@@ -0,0 +1,53 @@
+// checks shared by the tests of the sorting algorithms
+
+use std::fmt::Debug;
+
+// unsorted, sorted, reversed and duplicate heavy inputs every sorting algorithm must handle
+pub fn inputs() -> Vec<Vec<usize>> {
+    vec![
+        vec![],
+        vec![1],
+        vec![7; 100],
+        (0..100).collect(),
+        (0..100).rev().collect(),
+        (0..100)
+            .map(|i| if i % 10 == 0 { 100 - i } else { i })
+            .collect(),
+        (0..100).map(|i| i % 3).collect(),
+        (0..100).map(|i| (i * 37) % 101).collect(),
+    ]
+}
+
+// asserts that `sort` orders every input the same way as the standard library sort
+pub fn assert_sorts<T, F>(inputs: Vec<Vec<T>>, sort: F)
+where
+    T: Ord + Clone + Debug,
+    F: Fn(&mut [T]),
+{
+    for input in inputs {
+        let mut vec = input.clone();
+        let mut accept = input;
+        accept.sort();
+
+        sort(&mut vec);
+
+        assert_eq!(vec, accept);
+    }
+}
+
+// asserts that `sort` keeps the original order of pairs with equal keys, when it sorts pairs of (key, original position) by key
+pub fn assert_stable<F>(sort: F)
+where
+    F: Fn(&mut [(usize, usize)]),
+{
+    let mut vec: Vec<(usize, usize)> = (0..100).map(|i| ((i * 7) % 5, i)).collect();
+
+    sort(&mut vec);
+
+    for window in vec.windows(2) {
+        assert!(window[0].0 <= window[1].0);
+        if window[0].0 == window[1].0 {
+            assert!(window[0].1 < window[1].1);
+        }
+    }
+}
@@ -2,6 +2,9 @@ use std::cmp::{Ord, Ordering};
 
 /// Partitions the slice around the element at `pivot_index`.
 /// Returns index of pivot after partitioning
+///
+/// Items smaller than the pivot are moved before it and the rest are moved after it.
+/// It uses the Lomuto partition scheme
 /// 
 /// # Arguments
 /// * `slice`: slice of data to be partitioned
@@ -30,6 +33,8 @@ pub fn partition<T: Ord>(slice: &mut [T], pivot_index: usize) -> usize {
 /// * `slice`: slice of data to be partitioned
 /// * `pivot_index`: index of the pivot. slice will be partitioned around item at this index
 /// * `compare`: custom comparing closure
+///
+/// It uses the Lomuto partition scheme
 /// 
 /// # Examples
 /// ```
@@ -67,3 +72,45 @@ where
 
     i
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_partitioned<T: Ord>(slice: &[T], pivot_index: usize) -> bool {
+        slice[..pivot_index].iter().all(|item| *item <= slice[pivot_index])
+            && slice[pivot_index + 1..].iter().all(|item| *item >= slice[pivot_index])
+    }
+
+    #[test]
+    fn algo_transform_partition_1() {
+        let inputs: Vec<Vec<usize>> = vec![
+            (0..20).collect(),
+            (0..20).rev().collect(),
+            (0..20).map(|i| (i * 7) % 20).collect(),
+            (0..20).map(|i| i % 3).collect(),
+        ];
+
+        for input in inputs {
+            for pivot_index in 0..input.len() {
+                let mut vec = input.clone();
+                let pivot = vec[pivot_index];
+
+                let new_pivot_index = partition(&mut vec, pivot_index);
+
+                assert_eq!(vec[new_pivot_index], pivot);
+                assert!(is_partitioned(&vec, new_pivot_index));
+            }
+        }
+    }
+
+    #[test]
+    fn algo_transform_partition_2() {
+        let mut vec = vec![1];
+        assert_eq!(partition(&mut vec, 0), 0);
+
+        let mut vec = vec![5, 5, 5, 5];
+        assert_eq!(partition(&mut vec, 2), 0);
+        assert_eq!(vec, vec![5, 5, 5, 5]);
+    }
+}
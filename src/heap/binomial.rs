@@ -26,7 +26,7 @@ use crate::tree::BinomialTree;
 ///     format!("Rank 0: \nRank 1: \nRank 2: 0 1 2 3\n")
 /// );
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BinomialHeap<T: std::cmp::Ord> {
     // stores binomial trees of different ranks
     // index of the vector represents the rank of the tree
@@ -97,6 +97,8 @@ impl<T: std::cmp::Ord> BinomialHeap<T> {
     /// * `binomial_heap_1`: first binomial heap
     /// * `binomial_heap_2`: second binomial heap
     ///
+    /// If one of the heaps is empty, the other heap is returned unchanged.
+    ///
     /// # Panics:
     /// * panics if two binomial heaps are not the same kind(ex. one is min heap and the other is max heap)
     ///
@@ -112,14 +114,23 @@ impl<T: std::cmp::Ord> BinomialHeap<T> {
     /// assert_eq!(BinomialHeap::preorder(&merged_heap), String::from("Rank 0: \nRank 1: 0 1\n"))
     /// ```
     pub fn merge(
-        binomial_heap_1: BinomialHeap<T>,
-        binomial_heap_2: BinomialHeap<T>,
+        mut binomial_heap_1: BinomialHeap<T>,
+        mut binomial_heap_2: BinomialHeap<T>,
     ) -> BinomialHeap<T> {
         // two binomial heaps must be of same kind in order for merge to be possible
         if binomial_heap_1.is_min() != binomial_heap_2.is_min() {
             panic!("Both binomial heaps must be of the same type(both min or both max)");
         }
 
+        // if either heaps are empty, return the other one as result
+        if binomial_heap_1.is_empty() {
+            binomial_heap_2.candidate_root_index = binomial_heap_2.find_candidate_root_index();
+            return binomial_heap_2;
+        } else if binomial_heap_2.is_empty() {
+            binomial_heap_1.candidate_root_index = binomial_heap_1.find_candidate_root_index();
+            return binomial_heap_1;
+        }
+
         // for less trouble iterate over the smaller heap and insert its binomial trees into the second(larger) heap
         // this helps cause: there will be no index out of bound in the larger(when calling _push).
         // because every rank present in the smaller heap is also present in the larger heap.
@@ -183,6 +194,15 @@ impl<T: std::cmp::Ord> BinomialHeap<T> {
         // iteration for pushing must start at the rank of the new node
         let start_rank = new_node.rank();
 
+        // if there is no rank as large as the rank of new node(ex. heap was cleared), allocate ranks up to it and insert the new node there
+        if start_rank >= max_rank {
+            self.roots.resize_with(start_rank, || None);
+            self.roots.push(Some(new_node));
+
+            self.candidate_root_index = self.find_candidate_root_index();
+            return;
+        }
+
         // it will iterate till it reaches maximum rank
         // if it passes the maximum rank(merging trees continue that far), a larger rank will be allocated
         for i in start_rank..max_rank {
@@ -230,6 +250,38 @@ impl<T: std::cmp::Ord> BinomialHeap<T> {
         // find index of root with highest priority
         let candidate_index = self.find_candidate_root_index();
 
+        Some(self.pop_root(candidate_index))
+    }
+
+    /// Pops and returns item with highest priority. Returns `None` if heap is empty.
+    ///
+    /// When roots of several binomial trees share the highest priority, the root of the tree with the lowest rank is popped.
+    /// Therefore the same sequence of operations always pops equal-priority items in the same order
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::BinomialHeap;
+    ///
+    /// let mut binomial_heap = BinomialHeap::init_min((1, "a"));
+    /// binomial_heap.push((0, "b"));
+    /// binomial_heap.push((1, "c"));
+    ///
+    /// assert_eq!(binomial_heap.pop_stable(), Some((0, "b")));
+    /// assert_eq!(binomial_heap.size(), 2);
+    /// ```
+    pub fn pop_stable(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // find index of root with highest priority and lowest rank
+        let candidate_index = self.find_stable_candidate_root_index();
+
+        Some(self.pop_root(candidate_index))
+    }
+
+    // removes root of the tree with rank `candidate_index` and returns its payload
+    fn pop_root(&mut self, candidate_index: usize) -> T {
         // extract the node from heap
         let mut popped_node = self.roots[candidate_index].take().unwrap();
 
@@ -242,8 +294,11 @@ impl<T: std::cmp::Ord> BinomialHeap<T> {
 
         self.size -= 1;
 
+        // popped root may not have had any children to trigger an update of the candidate index
+        self.candidate_root_index = self.find_candidate_root_index();
+
         // return payload the popped node
-        Some(popped_node.get_payload())
+        popped_node.get_payload()
     }
 
     /// Returns a reference to item with highest priority
@@ -269,10 +324,72 @@ impl<T: std::cmp::Ord> BinomialHeap<T> {
             return &None;
         }
 
-        self.roots[self.candidate_root_index]
-            .as_ref()
-            .unwrap()
-            .peek_payload()
+        match self.roots.get(self.candidate_root_index) {
+            Some(Some(binomial_tree)) => binomial_tree.peek_payload(),
+            _ => &None,
+        }
+    }
+
+    /// Returns a reference to the smallest item in the heap regardless of heap being a min or max heap.
+    /// Returns `None` if heap is empty
+    /// * Complexity: O(log n) for min heaps and O(n) for max heaps
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::BinomialHeap;
+    ///
+    /// let mut binomial_heap = BinomialHeap::init_max(3);
+    /// binomial_heap.push(1);
+    /// binomial_heap.push(2);
+    ///
+    /// assert_eq!(binomial_heap.peek_min(), Some(&1));
+    /// ```
+    pub fn peek_min(&self) -> Option<&T> {
+        if self.is_min() {
+            // in a min heap, smallest item is one of the roots
+            self.roots
+                .iter()
+                .flatten()
+                .filter_map(|tree| tree.peek_payload().as_ref())
+                .min()
+        } else {
+            self.roots
+                .iter()
+                .flatten()
+                .flat_map(|tree| tree.iter_preorder())
+                .min()
+        }
+    }
+
+    /// Returns a reference to the largest item in the heap regardless of heap being a min or max heap.
+    /// Returns `None` if heap is empty
+    /// * Complexity: O(log n) for max heaps and O(n) for min heaps
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::BinomialHeap;
+    ///
+    /// let mut binomial_heap = BinomialHeap::init_min(3);
+    /// binomial_heap.push(1);
+    /// binomial_heap.push(2);
+    ///
+    /// assert_eq!(binomial_heap.peek_max(), Some(&3));
+    /// ```
+    pub fn peek_max(&self) -> Option<&T> {
+        if self.is_max() {
+            // in a max heap, largest item is one of the roots
+            self.roots
+                .iter()
+                .flatten()
+                .filter_map(|tree| tree.peek_payload().as_ref())
+                .max()
+        } else {
+            self.roots
+                .iter()
+                .flatten()
+                .flat_map(|tree| tree.iter_preorder())
+                .max()
+        }
     }
 
     /// Clears the heap and resets internal flags
@@ -291,6 +408,38 @@ impl<T: std::cmp::Ord> BinomialHeap<T> {
     pub fn clear(&mut self) {
         self.roots.clear();
         self.size = 0;
+        self.candidate_root_index = 0;
+    }
+
+    /// Restores the invariants of the heap: every binomial tree is moved to the slot of its rank,
+    /// trees of equal rank are merged and the root with highest priority is located again.
+    /// Useful after the trees of the heap are mutated in bulk
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::BinomialHeap;
+    ///
+    /// let mut binomial_heap = BinomialHeap::init_min(2);
+    /// binomial_heap.push(1);
+    /// binomial_heap.push(0);
+    ///
+    /// binomial_heap.rebuild();
+    ///
+    /// assert_eq!(*binomial_heap.peek(), Some(0));
+    /// assert_eq!(
+    ///     BinomialHeap::preorder(&binomial_heap),
+    ///     String::from("Rank 0: 0\nRank 1: 1 2\n")
+    /// );
+    /// ```
+    pub fn rebuild(&mut self) {
+        let binomial_trees: Vec<BinomialTree<T>> = self.roots.drain(..).flatten().collect();
+
+        self.candidate_root_index = 0;
+
+        // pushing a tree places it at the slot of its rank and merges it with any tree of the same rank
+        for binomial_tree in binomial_trees {
+            self._push(binomial_tree);
+        }
     }
 
     /// Returns number of items in heap
@@ -357,12 +506,66 @@ impl<T: std::cmp::Ord> BinomialHeap<T> {
         self.size() == 0
     }
 
+    /// Returns number of binomial trees in the heap.
+    /// Since the heap holds at most one tree of each rank, it is equal to number of set bits of `size`
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::BinomialHeap;
+    ///
+    /// let mut binomial_heap = BinomialHeap::init_min(0);
+    /// binomial_heap.push(1);
+    /// binomial_heap.push(2);
+    ///
+    /// // one tree of rank 0 and one tree of rank 1
+    /// assert_eq!(binomial_heap.num_trees(), 2);
+    /// ```
+    pub fn num_trees(&self) -> usize {
+        self.roots.iter().filter(|root| root.is_some()).count()
+    }
+
     fn max_tree_rank(&self) -> usize {
         self.roots.len()
     }
 
+    // find index of root with highest priority. among roots with equal priority, the one with lowest rank is chosen
+    fn find_stable_candidate_root_index(&self) -> usize {
+        let mut candidate_index: Option<usize> = None;
+
+        for (i, root) in self.roots.iter().enumerate() {
+            let node = match root {
+                Some(node) => node,
+                None => continue,
+            };
+
+            // candidate is only replaced by a root with strictly higher priority
+            let has_higher_priority = match candidate_index {
+                None => true,
+                Some(index) => {
+                    let candidate = self.roots[index].as_ref().unwrap();
+                    if self.is_min() {
+                        !BinomialTree::is_greater_or_equal(node, candidate)
+                    } else {
+                        !BinomialTree::is_smaller_or_equal(node, candidate)
+                    }
+                }
+            };
+
+            if has_higher_priority {
+                candidate_index = Some(i);
+            }
+        }
+
+        candidate_index.unwrap()
+    }
+
     // find index of root with highest priority(minimum root in min heap and maximum root in max heap)
     fn find_candidate_root_index(&self) -> usize {
+        // an empty heap(ex. a default or cleared heap) has no roots to choose from
+        if self.roots.is_empty() {
+            return 0;
+        }
+
         // candidate index to pop the item with largest priority
         let mut candidate_index = 0;
 
@@ -455,6 +658,55 @@ impl<T: std::cmp::Ord + std::fmt::Display> BinomialHeap<T> {
     }
 }
 
+impl<T: std::cmp::Ord + std::fmt::Display> std::fmt::Display for BinomialHeap<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", BinomialHeap::preorder(self))
+    }
+}
+
+/// Initializes an empty min heap
+///
+/// # Examples
+/// ```
+/// use rudac::heap::BinomialHeap;
+///
+/// let mut binomial_heap: BinomialHeap<usize> = BinomialHeap::default();
+///
+/// assert!(binomial_heap.is_min());
+/// assert!(binomial_heap.is_empty());
+/// ```
+impl<T: std::cmp::Ord> Default for BinomialHeap<T> {
+    fn default() -> BinomialHeap<T> {
+        BinomialHeap {
+            roots: Vec::new(),
+            candidate_root_index: 0,
+            size: 0,
+            min: true,
+        }
+    }
+}
+
+/// Pushes every item of the iterator into the heap
+///
+/// # Examples
+/// ```
+/// use rudac::heap::BinomialHeap;
+///
+/// let mut binomial_heap: BinomialHeap<usize> = BinomialHeap::default();
+///
+/// binomial_heap.extend(vec![3, 1, 2]);
+///
+/// assert_eq!(binomial_heap.size(), 3);
+/// assert_eq!(binomial_heap.pop(), Some(1));
+/// ```
+impl<T: std::cmp::Ord> Extend<T> for BinomialHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for payload in iter {
+            self.push(payload);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -867,6 +1119,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn heap_binomial_merge_empty_1() {
+        let mut empty_heap = BinomialHeap::init_min(0);
+        empty_heap.pop();
+
+        let mut bh = BinomialHeap::init_min(3);
+        bh.push(1);
+        bh.push(2);
+
+        let mut merged_heap = BinomialHeap::merge(empty_heap, bh);
+
+        assert_eq!(merged_heap.size(), 3);
+        assert_eq!(*merged_heap.peek(), Some(1));
+        assert_eq!(merged_heap.pop(), Some(1));
+        assert_eq!(merged_heap.pop(), Some(2));
+        assert_eq!(merged_heap.pop(), Some(3));
+        assert_eq!(merged_heap.pop(), None);
+    }
+
+    #[test]
+    fn heap_binomial_merge_empty_2() {
+        let mut empty_heap = BinomialHeap::init_max(0);
+        empty_heap.clear();
+
+        let mut bh = BinomialHeap::init_max(3);
+        bh.push(1);
+        bh.push(2);
+
+        let mut merged_heap = BinomialHeap::merge(bh, empty_heap);
+
+        assert_eq!(merged_heap.size(), 3);
+        assert_eq!(*merged_heap.peek(), Some(3));
+        assert_eq!(merged_heap.pop(), Some(3));
+        assert_eq!(merged_heap.pop(), Some(2));
+        assert_eq!(merged_heap.pop(), Some(1));
+        assert_eq!(merged_heap.pop(), None);
+    }
+
     #[test]
     #[should_panic(expected = "Both binomial heaps must be of the same type(both min or both max)")]
     fn heap_binomial_panic_merge() {
@@ -874,4 +1164,280 @@ mod tests {
         let bh2 = BinomialHeap::init_max(1);
         BinomialHeap::merge(bh1, bh2);
     }
+
+    #[test]
+    fn heap_binomial_display() {
+        let mut bh = BinomialHeap::init_min(0);
+        for i in 1..7 {
+            bh.push(i);
+        }
+
+        assert_eq!(bh.to_string(), BinomialHeap::preorder(&bh));
+        assert_eq!(
+            format!("{}", bh),
+            "Rank 0: 6\nRank 1: 4 5\nRank 2: 0 1 2 3\n"
+        );
+    }
+
+    #[test]
+    fn heap_binomial_clear_1() {
+        let mut bh = BinomialHeap::init_min(0);
+        for i in 1..7 {
+            bh.push(i);
+        }
+
+        bh.clear();
+        assert_eq!(*bh.peek(), None);
+        assert_eq!(bh.pop(), None);
+
+        bh.push(10);
+        assert_eq!(bh.size(), 1);
+        assert_eq!(*bh.peek(), Some(10));
+        assert_eq!(bh.pop(), Some(10));
+        assert_eq!(bh.pop(), None);
+
+        for i in (0..10).rev() {
+            bh.push(i);
+        }
+        for i in 0..10 {
+            assert_eq!(bh.pop(), Some(i));
+        }
+        assert!(bh.is_empty());
+    }
+
+    #[test]
+    fn heap_binomial_peek_min_max_1() {
+        for mut bh in [BinomialHeap::init_min(50), BinomialHeap::init_max(50)] {
+            for i in (0..100).map(|i| (i * 37) % 101).filter(|i| *i != 50) {
+                bh.push(i);
+            }
+
+            assert_eq!(bh.peek_min(), Some(&0));
+            assert_eq!(bh.peek_max(), Some(&100));
+
+            bh.pop();
+
+            let (min, max) = if bh.is_min() { (1, 100) } else { (0, 99) };
+            assert_eq!(bh.peek_min(), Some(&min));
+            assert_eq!(bh.peek_max(), Some(&max));
+
+            bh.clear();
+            assert_eq!(bh.peek_min(), None);
+            assert_eq!(bh.peek_max(), None);
+        }
+    }
+
+    #[test]
+    fn heap_binomial_rebuild_1() {
+        let mut bh = BinomialHeap::init_min(0);
+        bh.rebuild();
+        assert_eq!(*bh.peek(), Some(0));
+
+        // 13 items are stored in trees of rank 0, 2 and 3
+        for i in 1..13 {
+            bh.push(i * 2);
+        }
+
+        // move trees out of the slots of their ranks, add a second tree of rank 0 and invalidate the candidate
+        bh.roots.reverse();
+        let empty_slot = bh.roots.iter().position(|root| root.is_none()).unwrap();
+        bh.roots[empty_slot] = Some(BinomialTree::init(-1, true));
+        bh.size += 1;
+        bh.candidate_root_index = 0;
+
+        bh.rebuild();
+
+        for (rank, root) in bh.roots.iter().enumerate() {
+            if let Some(binomial_tree) = root {
+                assert_eq!(binomial_tree.rank(), rank);
+            }
+        }
+        assert_eq!(*bh.peek(), Some(-1));
+        assert_eq!(bh.size(), 14);
+
+        assert_eq!(bh.pop(), Some(-1));
+        for i in 0..13 {
+            assert_eq!(bh.pop(), Some(i * 2));
+        }
+        assert_eq!(bh.pop(), None);
+
+        bh.rebuild();
+        assert_eq!(*bh.peek(), None);
+    }
+
+    #[test]
+    fn heap_binomial_default() {
+        let mut bh: BinomialHeap<usize> = BinomialHeap::default();
+
+        assert!(bh.is_empty());
+        assert!(bh.is_min());
+        assert_eq!(*bh.peek(), None);
+        assert_eq!(bh.pop(), None);
+
+        for i in (0..10).rev() {
+            bh.push(i);
+        }
+        assert_eq!(*bh.peek(), Some(0));
+        for i in 0..10 {
+            assert_eq!(bh.pop(), Some(i));
+        }
+        assert!(bh.is_empty());
+
+        let merged = BinomialHeap::merge(BinomialHeap::default(), BinomialHeap::init_min(1));
+        assert_eq!(*merged.peek(), Some(1));
+    }
+
+    #[test]
+    fn heap_binomial_num_trees() {
+        let mut bh = BinomialHeap::init_min(0);
+
+        for i in 1..300 {
+            assert_eq!(bh.num_trees(), bh.size().count_ones() as usize);
+            bh.push(i);
+        }
+
+        while !bh.is_empty() {
+            assert_eq!(bh.num_trees(), bh.size().count_ones() as usize);
+            bh.pop();
+        }
+        assert_eq!(bh.num_trees(), 0);
+    }
+
+    #[test]
+    fn heap_binomial_extend() {
+        let mut bh: BinomialHeap<usize> = BinomialHeap::init_max(100);
+        bh.extend(0..50);
+        bh.extend(vec![75, 25]);
+
+        assert_eq!(bh.size(), 53);
+
+        let mut expected: Vec<usize> = (0..50).chain(vec![75, 25, 100]).collect();
+        expected.sort_by(|a, b| b.cmp(a));
+        for item in expected {
+            assert_eq!(bh.pop(), Some(item));
+        }
+        assert!(bh.is_empty());
+    }
+
+    #[test]
+    fn heap_binomial_clone() {
+        let mut bh = BinomialHeap::init_min(50);
+        bh.extend((0..100).map(|i| (i * 37) % 101));
+
+        let mut snapshot = bh.clone();
+        assert_eq!(snapshot.size(), bh.size());
+        assert_eq!(
+            BinomialHeap::preorder(&snapshot),
+            BinomialHeap::preorder(&bh)
+        );
+
+        let mut other = BinomialHeap::init_min(1000);
+        other.push(500);
+        snapshot = BinomialHeap::merge(snapshot, other);
+        assert_eq!(snapshot.size(), 103);
+        snapshot.pop();
+
+        let mut expected: Vec<usize> = (0..100).map(|i| (i * 37) % 101).collect();
+        expected.push(50);
+        expected.sort();
+
+        assert_eq!(bh.size(), 101);
+        for item in expected {
+            assert_eq!(bh.pop(), Some(item));
+        }
+        assert!(bh.is_empty());
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Task {
+        priority: usize,
+        id: usize,
+    }
+
+    // tasks are ordered by their priority only
+    impl PartialOrd for Task {
+        fn partial_cmp(&self, other: &Task) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Task {
+        fn cmp(&self, other: &Task) -> std::cmp::Ordering {
+            self.priority.cmp(&other.priority)
+        }
+    }
+
+    fn pop_stable_order() -> Vec<(usize, usize)> {
+        let mut bh = BinomialHeap::init_min(Task { priority: 1, id: 0 });
+        for id in 1..100 {
+            bh.push(Task {
+                priority: id % 3,
+                id,
+            });
+        }
+
+        let mut order = Vec::new();
+        while let Some(task) = bh.pop_stable() {
+            order.push((task.priority, task.id));
+        }
+        order
+    }
+
+    #[test]
+    fn heap_binomial_pop_stable() {
+        let order = pop_stable_order();
+
+        assert_eq!(order.len(), 100);
+        assert!(order.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+
+        let mut ids: Vec<usize> = order.iter().map(|(_, id)| *id).collect();
+        ids.sort();
+        assert!(ids.into_iter().eq(0..100));
+
+        // equal-priority tasks come out in the same order every time
+        for _ in 0..5 {
+            assert_eq!(pop_stable_order(), order);
+        }
+
+        // only one tree of each rank, so lowest rank wins the tie
+        let mut bh = BinomialHeap::init_min(Task { priority: 0, id: 0 });
+        bh.push(Task { priority: 0, id: 1 });
+        bh.push(Task { priority: 0, id: 2 });
+        assert_eq!(bh.pop_stable(), Some(Task { priority: 0, id: 2 }));
+    }
+
+    #[test]
+    fn heap_binomial_peek_after_pop() {
+        let mut bh = BinomialHeap::init_min(1);
+        bh.push(2);
+        bh.push(0);
+
+        // 0 is root of the rank 0 tree which has no children
+        assert_eq!(bh.pop(), Some(0));
+        assert_eq!(*bh.peek(), Some(1));
+        assert_eq!(bh.pop(), Some(1));
+        assert_eq!(*bh.peek(), Some(2));
+        assert_eq!(bh.pop(), Some(2));
+        assert_eq!(*bh.peek(), None);
+    }
+
+    #[test]
+    fn heap_binomial_merge_both_empty() {
+        let merged: BinomialHeap<usize> =
+            BinomialHeap::merge(BinomialHeap::default(), BinomialHeap::default());
+        assert!(merged.is_empty());
+        assert_eq!(*merged.peek(), None);
+
+        let mut bh1 = BinomialHeap::init_min(1);
+        let mut bh2 = BinomialHeap::init_min(2);
+        bh1.clear();
+        bh2.clear();
+
+        let mut merged = BinomialHeap::merge(bh1, bh2);
+        assert!(merged.is_empty());
+        assert_eq!(merged.pop(), None);
+
+        merged.push(3);
+        assert_eq!(*merged.peek(), Some(3));
+    }
 }
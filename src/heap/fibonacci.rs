@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct InternalTree<T: std::cmp::Ord> {
     // number of direct children of the current node
     degree: usize,
@@ -112,6 +112,14 @@ impl<T: std::cmp::Ord> InternalTree<T> {
     fn is_min(&self) -> bool {
         self.min
     }
+
+    // pushes references to all payloads of the tree into `payloads` in a preorder fashion
+    fn _payloads<'a>(internal_tree: &'a InternalTree<T>, payloads: &mut Vec<&'a T>) {
+        payloads.push(internal_tree.peek_payload());
+        for item in internal_tree.children_list() {
+            InternalTree::_payloads(item, payloads);
+        }
+    }
 }
 
 impl<T> InternalTree<T>
@@ -273,7 +281,7 @@ mod internal_tree_tests {
 ///     String::from("Priority: 0\nTree 1: 1\nTree 2: 3\n")
 /// )
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FibonacciHeap<T: std::cmp::Ord> {
     // doubly linked list of internal trees
     children_list: VecDeque<InternalTree<T>>,
@@ -382,6 +390,34 @@ impl<T: std::cmp::Ord> FibonacciHeap<T> {
         self.size += 1;
     }
 
+    /// Pushes all items yielded by `iter` into heap
+    ///
+    /// # Arguments:
+    /// * `iter`: items to be pushed into heap
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::FibonacciHeap;
+    ///
+    /// let mut fibonacci_heap: FibonacciHeap<usize> = FibonacciHeap::init_min();
+    ///
+    /// fibonacci_heap.push_all(vec![3, 1, 2]);
+    ///
+    /// assert_eq!(fibonacci_heap.size(), 3);
+    /// assert_eq!(fibonacci_heap.pop(), Some(1));
+    /// ```
+    pub fn push_all<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        // every pushed item becomes a root, so reserve room for them in children list up front
+        let (lower_bound, _) = iter.size_hint();
+        self.children_list.reserve(lower_bound);
+
+        for payload in iter {
+            self.push(payload);
+        }
+    }
+
     /// Merges two fibonacci heaps and returns the merged fibonacci heap
     ///
     /// # Arguments:
@@ -598,6 +634,48 @@ impl<T: std::cmp::Ord> FibonacciHeap<T> {
         Some(payload)
     }
 
+    /// Returns a reference to the smallest item in the heap regardless of heap being a min or max heap.
+    /// Returns `None` if heap is empty
+    /// * Complexity: O(1) for min heaps and O(n) for max heaps
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::FibonacciHeap;
+    ///
+    /// let mut fibonacci_heap: FibonacciHeap<usize> = FibonacciHeap::init_max();
+    /// fibonacci_heap.push_all(vec![3, 1, 2]);
+    ///
+    /// assert_eq!(fibonacci_heap.peek_min(), Some(&1));
+    /// ```
+    pub fn peek_min(&self) -> Option<&T> {
+        if self.is_min() {
+            self.peek()
+        } else {
+            self.iter().min()
+        }
+    }
+
+    /// Returns a reference to the largest item in the heap regardless of heap being a min or max heap.
+    /// Returns `None` if heap is empty
+    /// * Complexity: O(1) for max heaps and O(n) for min heaps
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::FibonacciHeap;
+    ///
+    /// let mut fibonacci_heap: FibonacciHeap<usize> = FibonacciHeap::init_min();
+    /// fibonacci_heap.push_all(vec![3, 1, 2]);
+    ///
+    /// assert_eq!(fibonacci_heap.peek_max(), Some(&3));
+    /// ```
+    pub fn peek_max(&self) -> Option<&T> {
+        if self.is_max() {
+            self.peek()
+        } else {
+            self.iter().max()
+        }
+    }
+
     /// Clears the heap and resets internal flags
     ///
     /// # Examples
@@ -679,6 +757,89 @@ impl<T: std::cmp::Ord> FibonacciHeap<T> {
     pub fn is_max(&self) -> bool {
         !self.is_min()
     }
+
+    /// Returns number of trees in the heap, including the tree holding the item with highest priority
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::FibonacciHeap;
+    ///
+    /// let mut fibonacci_heap: FibonacciHeap<usize> = FibonacciHeap::init_min();
+    /// fibonacci_heap.push_all(0..4);
+    /// assert_eq!(fibonacci_heap.num_trees(), 4);
+    ///
+    /// // pop consolidates the remaining trees
+    /// fibonacci_heap.pop();
+    /// assert_eq!(fibonacci_heap.num_trees(), 2);
+    /// ```
+    pub fn num_trees(&self) -> usize {
+        self.children_list.len() + usize::from(self.priority_pointer.is_some())
+    }
+
+    /// Returns true if all trees in the heap have distinct degrees, which is the case right after a consolidation
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::FibonacciHeap;
+    ///
+    /// let mut fibonacci_heap: FibonacciHeap<usize> = FibonacciHeap::init_min();
+    /// fibonacci_heap.push_all(0..4);
+    /// assert_eq!(fibonacci_heap.is_consolidated(), false);
+    ///
+    /// fibonacci_heap.pop();
+    /// assert_eq!(fibonacci_heap.is_consolidated(), true);
+    /// ```
+    pub fn is_consolidated(&self) -> bool {
+        let mut seen_degrees = vec![false; self.num_trees()];
+
+        let trees = self
+            .priority_pointer
+            .iter()
+            .chain(self.children_list.iter());
+
+        for tree in trees {
+            let degree = tree.degree();
+
+            // degree of a tree can exceed number of trees, e.g. a heap with a single tree of degree 3
+            if degree >= seen_degrees.len() {
+                seen_degrees.resize(degree + 1, false);
+            }
+
+            if seen_degrees[degree] {
+                return false;
+            }
+            seen_degrees[degree] = true;
+        }
+
+        true
+    }
+
+    /// Returns an iterator over references to all items in the heap in arbitrary order
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::FibonacciHeap;
+    ///
+    /// let mut fibonacci_heap: FibonacciHeap<usize> = FibonacciHeap::init_min();
+    /// fibonacci_heap.push_all(0..10);
+    /// fibonacci_heap.pop();
+    ///
+    /// assert_eq!(fibonacci_heap.iter().count(), 9);
+    /// assert_eq!(fibonacci_heap.iter().sum::<usize>(), 45);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut payloads = Vec::with_capacity(self.size());
+
+        if let Some(priority_tree) = &self.priority_pointer {
+            InternalTree::_payloads(priority_tree, &mut payloads);
+        }
+
+        for internal_tree in self.children_list.iter() {
+            InternalTree::_payloads(internal_tree, &mut payloads);
+        }
+
+        payloads.into_iter()
+    }
 }
 
 impl<T> FibonacciHeap<T>
@@ -732,6 +893,51 @@ where
     }
 }
 
+impl<T> std::fmt::Display for FibonacciHeap<T>
+where
+    T: std::cmp::Ord + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", FibonacciHeap::preorder(self))
+    }
+}
+
+/// Initializes an empty min heap
+///
+/// # Examples
+/// ```
+/// use rudac::heap::FibonacciHeap;
+///
+/// let mut fibonacci_heap: FibonacciHeap<usize> = FibonacciHeap::default();
+///
+/// assert!(fibonacci_heap.is_min());
+/// assert!(fibonacci_heap.is_empty());
+/// ```
+impl<T: std::cmp::Ord> Default for FibonacciHeap<T> {
+    fn default() -> FibonacciHeap<T> {
+        FibonacciHeap::init_min()
+    }
+}
+
+/// Pushes every item of the iterator into the heap
+///
+/// # Examples
+/// ```
+/// use rudac::heap::FibonacciHeap;
+///
+/// let mut fibonacci_heap: FibonacciHeap<usize> = FibonacciHeap::default();
+///
+/// fibonacci_heap.extend(vec![3, 1, 2]);
+///
+/// assert_eq!(fibonacci_heap.size(), 3);
+/// assert_eq!(fibonacci_heap.pop(), Some(1));
+/// ```
+impl<T: std::cmp::Ord> Extend<T> for FibonacciHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.push_all(iter);
+    }
+}
+
 #[cfg(test)]
 mod fibonacci_heap_tests {
     use super::*;
@@ -1104,4 +1310,156 @@ mod fibonacci_heap_tests {
         assert_eq!(fh.size(), 0);
         assert_eq!(FibonacciHeap::preorder(&fh), String::from(""));
     }
+
+    #[test]
+    fn heap_fibonacci_push_all_1() {
+        let mut fh: FibonacciHeap<usize> = FibonacciHeap::init_min();
+        fh.push_all((0..100).rev());
+
+        assert_eq!(fh.size(), 100);
+        for i in 0..100 {
+            assert_eq!(fh.pop(), Some(i));
+        }
+        assert!(fh.is_empty());
+
+        let mut fh: FibonacciHeap<usize> = FibonacciHeap::init_max();
+        fh.push_all(0..100);
+
+        assert_eq!(fh.size(), 100);
+        for i in (0..100).rev() {
+            assert_eq!(fh.pop(), Some(i));
+        }
+        assert!(fh.is_empty());
+    }
+
+    #[test]
+    fn heap_fibonacci_display() {
+        let mut fh: FibonacciHeap<usize> = FibonacciHeap::init_min();
+        for i in 0..5 {
+            fh.push(i);
+        }
+        fh.pop();
+
+        assert_eq!(fh.to_string(), FibonacciHeap::preorder(&fh));
+        assert_eq!(format!("{}", fh), "Priority: 1 2 3 4\n");
+    }
+
+    #[test]
+    fn heap_fibonacci_iter_1() {
+        let mut fh: FibonacciHeap<usize> = FibonacciHeap::init_max();
+        assert_eq!(fh.iter().count(), 0);
+
+        fh.push_all(0..50);
+        fh.pop();
+        fh.push(100);
+
+        let mut payloads: Vec<usize> = fh.iter().cloned().collect();
+        payloads.sort();
+
+        let mut accept: Vec<usize> = (0..49).collect();
+        accept.push(100);
+
+        assert_eq!(payloads.len(), fh.size());
+        assert_eq!(payloads, accept);
+    }
+
+    #[test]
+    fn heap_fibonacci_peek_min_max_1() {
+        for mut fh in [FibonacciHeap::init_min(), FibonacciHeap::init_max()] {
+            assert_eq!(fh.peek_min(), None);
+            assert_eq!(fh.peek_max(), None);
+
+            fh.push_all((0..100).map(|i| (i * 37) % 101));
+            // consolidate the heap so items are spread across the trees
+            fh.pop();
+
+            let (min, max) = if fh.is_min() { (1, 100) } else { (0, 99) };
+            assert_eq!(fh.peek_min(), Some(&min));
+            assert_eq!(fh.peek_max(), Some(&max));
+        }
+    }
+
+    #[test]
+    fn heap_fibonacci_default() {
+        let mut fh: FibonacciHeap<usize> = FibonacciHeap::default();
+
+        assert!(fh.is_empty());
+        assert!(fh.is_min());
+        assert_eq!(fh.pop(), None);
+
+        for i in (0..10).rev() {
+            fh.push(i);
+        }
+        for i in 0..10 {
+            assert_eq!(fh.pop(), Some(i));
+        }
+        assert!(fh.is_empty());
+    }
+
+    #[test]
+    fn heap_fibonacci_num_trees_and_is_consolidated() {
+        let mut fh: FibonacciHeap<usize> = FibonacciHeap::init_min();
+        assert_eq!(fh.num_trees(), 0);
+        assert!(fh.is_consolidated());
+
+        fh.push_all((0..1000).map(|i| (i * 37) % 1009));
+        assert_eq!(fh.num_trees(), 1000);
+        assert!(!fh.is_consolidated());
+
+        fh.consolidate();
+        assert!(fh.is_consolidated());
+        assert_eq!(fh.num_trees(), 1000_usize.count_ones() as usize);
+
+        while fh.size() > 1 {
+            fh.pop();
+
+            // trees of distinct degrees, each one at least as large as a binomial tree of its degree
+            let log_size = (fh.size() as f64).log2() as usize;
+            assert!(fh.is_consolidated());
+            assert!(fh.num_trees() <= log_size + 1);
+        }
+    }
+
+    #[test]
+    fn heap_fibonacci_extend() {
+        let mut fh: FibonacciHeap<usize> = FibonacciHeap::default();
+        fh.extend((0..100).rev());
+        fh.extend(vec![50, 150]);
+
+        assert_eq!(fh.size(), 102);
+
+        let mut expected: Vec<usize> = (0..100).chain(vec![50, 150]).collect();
+        expected.sort();
+        for item in expected {
+            assert_eq!(fh.pop(), Some(item));
+        }
+        assert!(fh.is_empty());
+    }
+
+    #[test]
+    fn heap_fibonacci_clone() {
+        let mut fh: FibonacciHeap<usize> = FibonacciHeap::init_max();
+        fh.push_all((0..500).map(|i| (i * 37) % 503));
+
+        // popping consolidates the root list into trees with children
+        assert_eq!(fh.pop(), Some(502));
+        assert!(fh.is_consolidated());
+
+        let mut snapshot = fh.clone();
+        assert_eq!(snapshot.size(), fh.size());
+        assert_eq!(FibonacciHeap::preorder(&snapshot), FibonacciHeap::preorder(&fh));
+
+        snapshot.push(1000);
+        for _ in 0..100 {
+            snapshot.pop();
+        }
+        assert_eq!(snapshot.size(), 400);
+
+        let mut expected: Vec<usize> = (0..500).map(|i| (i * 37) % 503).collect();
+        expected.sort_by(|a, b| b.cmp(a));
+        for item in expected.into_iter().skip(1) {
+            assert_eq!(fh.pop(), Some(item));
+        }
+        assert!(fh.is_empty());
+    }
 }
@@ -1,3 +1,5 @@
+use crate::algo::find::kth;
+
 /// A min-max heap provides constant time retrieval and logarithmic time removal of both the min and max elements in it.
 /// This makes the min-max heap a very useful data structure to implement a double-ended priority queue
 ///
@@ -22,6 +24,23 @@ pub struct MinMax<T: std::cmp::Ord> {
     tree: Vec<T>,
 }
 
+/// Specifies which end of a min-max heap an operation should be performed on
+///
+/// # Examples
+/// ```
+/// use rudac::heap::{Extremum, MinMax};
+///
+/// let minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+///
+/// assert_eq!(*minmax.peek(Extremum::Min).unwrap(), 1);
+/// assert_eq!(*minmax.peek(Extremum::Max).unwrap(), 11);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extremum {
+    Min,
+    Max,
+}
+
 impl<T: std::cmp::Ord> MinMax<T> {
     /// Initializes a heap with zero capacity
     ///
@@ -71,17 +90,22 @@ impl<T: std::cmp::Ord> MinMax<T> {
     pub fn build_heap(vector: Vec<T>) -> MinMax<T> {
         let mut minmax_heap = MinMax { tree: vector };
 
+        minmax_heap.heapify();
+
+        minmax_heap
+    }
+
+    // restores the heap property of the whole tree bottom-up
+    fn heapify(&mut self) {
         // to achieve O(n) complexity, method must traverse only inner nodes and escape leaves
         // thus it should iterate over from last inner node till the root
         // half of the nodes are leaves thus size / 2 shows the position of first leaf(size / 2 ... size are leaves)
-        let upper_bound = minmax_heap.size() / 2;
+        let upper_bound = self.size() / 2;
 
         for i in (0..upper_bound).rev() {
             // push down inner nodes
-            minmax_heap.push_down(i);
+            self.push_down(i);
         }
-
-        minmax_heap
     }
 
     // pushes down a node down the heap
@@ -299,6 +323,32 @@ impl<T: std::cmp::Ord> MinMax<T> {
         self.push_up(self.tree.len() - 1);
     }
 
+    /// Pushes all items of `iter` into the heap and restores the heap once using a bottom-up approach.
+    /// It is faster than pushing the items one by one when pushing large batches
+    /// * Complexity: O(n + m) where m is the number of pushed items
+    ///
+    /// # Arguments
+    /// * `iter`: items to be pushed into the heap
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let mut minmax: MinMax<usize> = MinMax::init();
+    ///
+    /// minmax.push(10);
+    /// minmax.push_all(vec![5, 1, 4, 3, 12]);
+    ///
+    /// assert_eq!(minmax.size(), 6);
+    /// assert_eq!(*minmax.peek_min().unwrap(), 1);
+    /// assert_eq!(*minmax.peek_max().unwrap(), 12);
+    /// ```
+    pub fn push_all<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.tree.extend(iter);
+
+        self.heapify();
+    }
+
     // bubbles up a node until heap property is restored
     fn push_up(&mut self, index: usize) {
         // nodes other than root can be pushed up
@@ -393,6 +443,26 @@ impl<T: std::cmp::Ord> MinMax<T> {
         }
     }
 
+    /// Returns references to the min and max values. returns None if heap is empty.
+    /// If there is only one item in the heap, both references point to it
+    /// * Complexity: O(1)
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// assert_eq!(minmax.peek_min_max(), Some((&1, &11)));
+    /// ```
+    pub fn peek_min_max(&self) -> Option<(&T, &T)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some((&self.tree[0], &self.tree[self.find_max_index()]))
+    }
+
     /// Pops min value from heap and returns it. returns None if heap is empty
     /// * Complexity: O(log n)
     ///
@@ -437,23 +507,125 @@ impl<T: std::cmp::Ord> MinMax<T> {
             1 | 2 => Some(self.tree.pop().unwrap()), // if there are only 1 or 2 item, max is at the end of the heap
             _ => {
                 // if there are more than 2 items, max is at index 1 or 2
-                let mut last_item: T;
+                let max_index = self.find_max_index();
 
-                if self.tree[1] > self.tree[2] {
-                    last_item = self.tree.pop().unwrap(); // pop last leaf
-                    std::mem::swap(&mut last_item, &mut self.tree[1]); // swap max with leaf
-                    self.push_down(1); // push down leaf until heap property is restored
-                } else {
-                    last_item = self.tree.pop().unwrap();
-                    std::mem::swap(&mut last_item, &mut self.tree[2]);
-                    self.push_down(2);
+                let mut last_item = self.tree.pop().unwrap(); // pop last leaf
+
+                // if max is the last leaf itself, it's already removed
+                if max_index == self.size() {
+                    return Some(last_item);
                 }
 
+                std::mem::swap(&mut last_item, &mut self.tree[max_index]); // swap max with leaf
+                self.push_down(max_index); // push down leaf until heap property is restored
+
                 Some(last_item)
             }
         }
     }
 
+    /// Pops min values from heap as long as `pred` returns true for them and returns the popped values in ascending order.
+    /// Popping stops at the first min value for which `pred` returns false, that value remains in the heap
+    /// * Complexity: O(k log n) where k is the number of popped values
+    ///
+    /// # Arguments
+    /// * `pred`: predicate deciding whether the current min value should be popped
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let mut minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// assert_eq!(minmax.pop_min_while(|item| *item < 4), vec![1, 2, 3]);
+    /// assert_eq!(*minmax.peek_min().unwrap(), 4);
+    /// ```
+    pub fn pop_min_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut popped = Vec::new();
+
+        while let Some(min) = self.peek_min() {
+            if !pred(min) {
+                break;
+            }
+            popped.push(self.pop_min().unwrap());
+        }
+
+        popped
+    }
+
+    /// Pops max values from heap as long as `pred` returns true for them and returns the popped values in descending order.
+    /// Popping stops at the first max value for which `pred` returns false, that value remains in the heap
+    /// * Complexity: O(k log n) where k is the number of popped values
+    ///
+    /// # Arguments
+    /// * `pred`: predicate deciding whether the current max value should be popped
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let mut minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// assert_eq!(minmax.pop_max_while(|item| *item > 7), vec![11, 9, 8]);
+    /// assert_eq!(*minmax.peek_max().unwrap(), 7);
+    /// ```
+    pub fn pop_max_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut popped = Vec::new();
+
+        while let Some(max) = self.peek_max() {
+            if !pred(max) {
+                break;
+            }
+            popped.push(self.pop_max().unwrap());
+        }
+
+        popped
+    }
+
+    /// Returns a reference to the min or max value depending on `kind`. returns None if heap is empty
+    /// * Complexity: O(1)
+    ///
+    /// # Arguments
+    /// * `kind`: which end of the heap to peek
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::{Extremum, MinMax};
+    ///
+    /// let minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// assert_eq!(*minmax.peek(Extremum::Min).unwrap(), 1);
+    /// assert_eq!(*minmax.peek(Extremum::Max).unwrap(), 11);
+    /// ```
+    pub fn peek(&self, kind: Extremum) -> Option<&T> {
+        match kind {
+            Extremum::Min => self.peek_min(),
+            Extremum::Max => self.peek_max(),
+        }
+    }
+
+    /// Pops min or max value depending on `kind` from heap and returns it. returns None if heap is empty
+    /// * Complexity: O(log n)
+    ///
+    /// # Arguments
+    /// * `kind`: which end of the heap to pop
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::{Extremum, MinMax};
+    ///
+    /// let mut minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// assert_eq!(minmax.pop(Extremum::Min).unwrap(), 1);
+    /// assert_eq!(minmax.pop(Extremum::Max).unwrap(), 11);
+    /// ```
+    pub fn pop(&mut self, kind: Extremum) -> Option<T> {
+        match kind {
+            Extremum::Min => self.pop_min(),
+            Extremum::Max => self.pop_max(),
+        }
+    }
+
     /// Pops and returns the min value and pushes the `item` into heap without allocating.
     /// * Complexity: O(log n)
     ///
@@ -602,6 +774,58 @@ impl<T: std::cmp::Ord> MinMax<T> {
         // return max
         Some(item)
     }
+
+    /// Pops and returns the min or max value depending on `kind` and pushes the `item` into heap without allocating.
+    /// * Complexity: O(log n)
+    ///
+    /// # Arguments
+    /// * `kind`: which end of the heap to pop
+    /// * `item`: data to be pushed into the heap
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::{Extremum, MinMax};
+    ///
+    /// let mut minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 0]);
+    ///
+    /// assert_eq!(minmax.push_pop(Extremum::Min, 13).unwrap(), 0);
+    /// assert_eq!(minmax.push_pop(Extremum::Max, 1).unwrap(), 13);
+    /// assert_eq!(*minmax.peek_min().unwrap(), 1);
+    /// assert_eq!(*minmax.peek_max().unwrap(), 11);
+    /// ```
+    pub fn push_pop(&mut self, kind: Extremum, item: T) -> Option<T> {
+        match kind {
+            Extremum::Min => self.push_pop_min(item),
+            Extremum::Max => self.push_pop_max(item),
+        }
+    }
+
+    /// Pops and returns the min or max value depending on `kind` and pushes the `item` into heap without allocating.
+    /// Unlike `push_pop`, the min or max value is popped before `item` is pushed
+    /// * Complexity: O(log n)
+    ///
+    /// # Arguments
+    /// * `kind`: which end of the heap to pop
+    /// * `item`: data to be pushed into the heap
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::{Extremum, MinMax};
+    ///
+    /// let mut minmax: MinMax<usize> = MinMax::build_heap(vec![3, 2, 1]);
+    ///
+    /// assert_eq!(minmax.replace(Extremum::Min, 4).unwrap(), 1);
+    /// assert_eq!(minmax.replace(Extremum::Max, 0).unwrap(), 4);
+    /// assert_eq!(*minmax.peek_min().unwrap(), 0);
+    /// assert_eq!(*minmax.peek_max().unwrap(), 3);
+    /// ```
+    pub fn replace(&mut self, kind: Extremum, item: T) -> Option<T> {
+        match kind {
+            Extremum::Min => self.replace_min(item),
+            Extremum::Max => self.replace_max(item),
+        }
+    }
+
     // find index of node with maximum value
     fn find_max_index(&self) -> usize {
         match self.size() {
@@ -619,6 +843,111 @@ impl<T: std::cmp::Ord> MinMax<T> {
         }
     }
 
+    /// Returns an iterator over references to the items of the heap in ascending order.
+    /// The heap itself is left untouched
+    /// * Complexity: O(n log n)
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// let sorted: Vec<&usize> = minmax.iter_sorted().collect();
+    /// assert_eq!(sorted, vec![&1, &2, &3, &4, &5, &6, &7, &8, &9, &11]);
+    ///
+    /// assert_eq!(*minmax.peek_min().unwrap(), 1);
+    /// ```
+    pub fn iter_sorted(&self) -> impl Iterator<Item = &T> {
+        let mut items: Vec<&T> = self.tree.iter().collect();
+        items.sort();
+
+        items.into_iter()
+    }
+
+    /// Returns the nth smallest item of the heap. n = 0 returns the min. returns None if n >= size().
+    /// Items are cloned into a temporary vector to select from, so the heap itself is left untouched
+    /// * Complexity: O(n)
+    ///
+    /// # Arguments
+    /// * `n`: order statistic, starting from 0
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// assert_eq!(minmax.nth_min(0), Some(1));
+    /// assert_eq!(minmax.nth_min(4), Some(5));
+    /// assert_eq!(minmax.nth_min(10), None);
+    /// ```
+    pub fn nth_min(&self, n: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        if n >= self.size() {
+            return None;
+        }
+
+        let mut items = self.tree.clone();
+        let index = kth(&mut items, n);
+
+        Some(items.swap_remove(index))
+    }
+
+    /// Returns the nth largest item of the heap. n = 0 returns the max. returns None if n >= size().
+    /// Items are cloned into a temporary vector to select from, so the heap itself is left untouched
+    /// * Complexity: O(n)
+    ///
+    /// # Arguments
+    /// * `n`: order statistic, starting from 0
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// assert_eq!(minmax.nth_max(0), Some(11));
+    /// assert_eq!(minmax.nth_max(4), Some(6));
+    /// assert_eq!(minmax.nth_max(10), None);
+    /// ```
+    pub fn nth_max(&self, n: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        if n >= self.size() {
+            return None;
+        }
+
+        self.nth_min(self.size() - 1 - n)
+    }
+
+    /// Retains only the items specified by the predicate `f` and restores the heap afterwards
+    /// * Complexity: O(n)
+    ///
+    /// # Arguments
+    /// * `f`: predicate that returns true for the items that must be kept
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let mut minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    ///
+    /// minmax.retain(|item| item % 2 == 0);
+    ///
+    /// assert_eq!(minmax.size(), 4);
+    /// assert_eq!(*minmax.peek_min().unwrap(), 2);
+    /// assert_eq!(*minmax.peek_max().unwrap(), 8);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.tree.retain(f);
+
+        self.heapify();
+    }
+
     /// Reserves capacity for `additional` more items to be pushed into heap
     pub fn reserve(&mut self, additional: usize) {
         self.tree.reserve(additional);
@@ -639,6 +968,60 @@ impl<T: std::cmp::Ord> MinMax<T> {
         self.tree
     }
 
+    /// Consumes the heap and moves its items into a max-heap of the standard library.
+    /// internal vector is handed over as is, so conversion takes O(n)
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let minmax = MinMax::build_heap(vec![3, 10, 1, 7]);
+    ///
+    /// let mut binary_heap = minmax.into_max_binary_heap();
+    ///
+    /// assert_eq!(binary_heap.pop(), Some(10));
+    /// assert_eq!(binary_heap.pop(), Some(7));
+    /// ```
+    pub fn into_max_binary_heap(self) -> std::collections::BinaryHeap<T> {
+        std::collections::BinaryHeap::from(self.tree)
+    }
+
+    /// Returns true if the min-max ordering holds for every item of the heap, false otherwise.
+    /// Items on min levels must be smaller than or equal to all of their descendants
+    /// and items on max levels must be greater than or equal to all of their descendants
+    /// * Complexity: O(n)
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let mut minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+    /// assert!(minmax.is_valid());
+    ///
+    /// minmax.pop_max();
+    /// minmax.replace_min(10);
+    /// assert!(minmax.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        // level of the index is computed exactly, without relying on floating point logarithm
+        let on_min_level = |index: usize| (usize::BITS - (index + 1).leading_zeros()) % 2 == 1;
+
+        // an item is bounded by all of its ancestors on the same kind of level through its grandparent,
+        // and by the ones on the other kind of level through its parent
+        let is_ordered = |ancestor: usize, index: usize| {
+            if on_min_level(ancestor) {
+                self.tree[ancestor] <= self.tree[index]
+            } else {
+                self.tree[ancestor] >= self.tree[index]
+            }
+        };
+
+        (1..self.size()).all(|index| {
+            is_ordered(parent(index), index)
+                && (!has_grandparent(index) || is_ordered(grandparent(index), index))
+        })
+    }
+
     /// Total number of elements in the heap
     pub fn size(&self) -> usize {
         self.tree.len()
@@ -649,11 +1032,29 @@ impl<T: std::cmp::Ord> MinMax<T> {
         self.size() == 0
     }
 
-    /// Clears the internal vector
+    /// Clears the internal vector. capacity of the heap is kept so it can be refilled without reallocating
     pub fn clear(&mut self) {
         self.tree.clear()
     }
 
+    /// Clears the heap and releases memory of the internal vector.
+    /// Unlike `clear`, capacity of the heap is 0 afterwards
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::heap::MinMax;
+    ///
+    /// let mut minmax = MinMax::build_heap(vec![3, 1, 2]);
+    ///
+    /// minmax.reset();
+    ///
+    /// assert!(minmax.is_empty());
+    /// assert_eq!(minmax.capacity(), 0);
+    /// ```
+    pub fn reset(&mut self) {
+        self.tree = Vec::new();
+    }
+
     /// Returns capacity of the heap
     pub fn capacity(&self) -> usize {
         self.tree.capacity()
@@ -1154,4 +1555,340 @@ mod tests {
         assert_eq!(*minmax.peek_min().unwrap(), 0);
         assert_eq!(*minmax.peek_max().unwrap(), 2);
     }
+
+    #[test]
+    fn heap_minmax_peek_1() {
+        let minmax: MinMax<usize> = MinMax::build_heap(vec![]);
+
+        assert_eq!(minmax.peek(Extremum::Min), None);
+        assert_eq!(minmax.peek(Extremum::Max), None);
+
+        let minmax = MinMax::build_heap(vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1]);
+
+        assert_eq!(minmax.peek(Extremum::Min), minmax.peek_min());
+        assert_eq!(minmax.peek(Extremum::Max), minmax.peek_max());
+    }
+
+    #[test]
+    fn heap_minmax_pop_1() {
+        let vector = vec![9, 8, 2, 3, 4, 5, 11, 6, 7, 1];
+        let mut minmax = MinMax::build_heap(vector.clone());
+        let mut dedicated = MinMax::build_heap(vector);
+
+        for kind in [Extremum::Min, Extremum::Max].iter().cycle().take(11) {
+            let expected = match kind {
+                Extremum::Min => dedicated.pop_min(),
+                Extremum::Max => dedicated.pop_max(),
+            };
+
+            assert_eq!(minmax.pop(*kind), expected);
+        }
+
+        assert!(minmax.is_empty());
+    }
+
+    #[test]
+    fn heap_minmax_iter_sorted_1() {
+        let minmax: MinMax<usize> = MinMax::build_heap(vec![]);
+        assert_eq!(minmax.iter_sorted().count(), 0);
+
+        let vector: Vec<usize> = (0..100).map(|i| (i * 37) % 101).collect();
+        let minmax = MinMax::build_heap(vector.clone());
+
+        let mut sorted = vector;
+        sorted.sort();
+
+        assert!(minmax.iter_sorted().eq(sorted.iter()));
+
+        assert_eq!(minmax.size(), 100);
+        assert_eq!(minmax.peek_min(), sorted.first());
+        assert_eq!(minmax.peek_max(), sorted.last());
+    }
+
+    #[test]
+    fn heap_minmax_push_pop_1() {
+        let vector: Vec<usize> = (0..50).map(|i| (i * 37) % 101).collect();
+        let mut minmax = MinMax::build_heap(vector.clone());
+        let mut dedicated = MinMax::build_heap(vector);
+
+        assert_eq!(
+            MinMax::build_heap(vec![]).push_pop(Extremum::Min, 1),
+            Some(1)
+        );
+
+        for (i, kind) in [Extremum::Min, Extremum::Max]
+            .iter()
+            .cycle()
+            .take(100)
+            .enumerate()
+        {
+            let item = (i * 53) % 103;
+            let expected = match kind {
+                Extremum::Min => dedicated.push_pop_min(item),
+                Extremum::Max => dedicated.push_pop_max(item),
+            };
+
+            assert_eq!(minmax.push_pop(*kind, item), expected);
+            assert_eq!(minmax.peek_min(), dedicated.peek_min());
+            assert_eq!(minmax.peek_max(), dedicated.peek_max());
+        }
+
+        assert!(minmax.iter_sorted().eq(dedicated.iter_sorted()));
+    }
+
+    #[test]
+    fn heap_minmax_replace_1() {
+        let vector: Vec<usize> = (0..50).map(|i| (i * 37) % 101).collect();
+        let mut minmax = MinMax::build_heap(vector.clone());
+        let mut dedicated = MinMax::build_heap(vector);
+
+        let mut empty = MinMax::build_heap(vec![]);
+        assert_eq!(empty.replace(Extremum::Max, 1), None);
+        assert_eq!(empty.size(), 1);
+
+        for (i, kind) in [Extremum::Min, Extremum::Max]
+            .iter()
+            .cycle()
+            .take(100)
+            .enumerate()
+        {
+            let item = (i * 53) % 103;
+            let expected = match kind {
+                Extremum::Min => dedicated.replace_min(item),
+                Extremum::Max => dedicated.replace_max(item),
+            };
+
+            assert_eq!(minmax.replace(*kind, item), expected);
+            assert_eq!(minmax.peek_min(), dedicated.peek_min());
+            assert_eq!(minmax.peek_max(), dedicated.peek_max());
+        }
+
+        assert!(minmax.iter_sorted().eq(dedicated.iter_sorted()));
+    }
+
+    #[test]
+    fn heap_minmax_peek_min_max_1() {
+        let minmax: MinMax<usize> = MinMax::build_heap(vec![]);
+        assert_eq!(minmax.peek_min_max(), None);
+
+        let minmax = MinMax::build_heap(vec![5]);
+        let (min, max) = minmax.peek_min_max().unwrap();
+        assert_eq!(*min, 5);
+        assert!(std::ptr::eq(min, max));
+
+        let minmax = MinMax::build_heap(vec![7, 3]);
+        assert_eq!(minmax.peek_min_max(), Some((&3, &7)));
+
+        let minmax = MinMax::build_heap(vec![4, 4]);
+        assert_eq!(minmax.peek_min_max(), Some((&4, &4)));
+    }
+
+    #[test]
+    fn heap_minmax_pop_max_last_leaf() {
+        // max is the last leaf of the heap
+        let mut minmax = MinMax::build_heap(vec![1, 2, 3]);
+
+        assert_eq!(minmax.pop_max(), Some(3));
+        assert_eq!(minmax.pop_max(), Some(2));
+        assert_eq!(minmax.pop_max(), Some(1));
+        assert_eq!(minmax.pop_max(), None);
+    }
+
+    #[test]
+    fn heap_minmax_peek_min_max_2() {
+        let mut minmax = MinMax::build_heap((0..100).map(|i| (i * 37) % 101).collect());
+
+        while !minmax.is_empty() {
+            assert_eq!(
+                minmax.peek_min_max(),
+                Some((minmax.peek_min().unwrap(), minmax.peek_max().unwrap()))
+            );
+
+            minmax.pop_max();
+        }
+
+        assert_eq!(minmax.peek_min_max(), None);
+    }
+
+    #[test]
+    fn heap_minmax_retain_1() {
+        let mut minmax = MinMax::build_heap((0..100).map(|i| (i * 37) % 101).collect());
+
+        minmax.retain(|item| item % 2 == 0);
+
+        assert_eq!(minmax.size(), 50);
+        assert_eq!(*minmax.peek_min().unwrap(), 0);
+        assert_eq!(*minmax.peek_max().unwrap(), 100);
+
+        let mut previous = minmax.pop_min().unwrap();
+        while let Some(item) = minmax.pop_min() {
+            assert_eq!(item % 2, 0);
+            assert!(previous < item);
+            previous = item;
+        }
+
+        minmax.retain(|_| false);
+        assert!(minmax.is_empty());
+    }
+
+    #[test]
+    fn heap_minmax_clear_keeps_capacity() {
+        let mut minmax = MinMax::with_capacity(100);
+        minmax.push(1);
+
+        minmax.clear();
+
+        assert!(minmax.is_empty());
+        assert!(minmax.capacity() >= 100);
+    }
+
+    #[test]
+    fn heap_minmax_reset() {
+        let mut minmax = MinMax::with_capacity(100);
+        minmax.push(1);
+
+        minmax.reset();
+
+        assert!(minmax.is_empty());
+        assert_eq!(minmax.capacity(), 0);
+
+        minmax.push(2);
+        assert_eq!(minmax.peek_min(), Some(&2));
+    }
+
+    #[test]
+    fn heap_minmax_push_all_1() {
+        let mut bulk = MinMax::build_heap(vec![500, 2000, 700]);
+        let mut one_by_one = MinMax::build_heap(vec![500, 2000, 700]);
+
+        let items: Vec<usize> = (0..1000).map(|i| (i * 37) % 1009).collect();
+
+        bulk.push_all(items.iter().copied());
+        for item in &items {
+            one_by_one.push(*item);
+        }
+
+        assert_eq!(bulk.size(), one_by_one.size());
+        assert_eq!(bulk.peek_min(), one_by_one.peek_min());
+        assert_eq!(bulk.peek_max(), one_by_one.peek_max());
+
+        while !bulk.is_empty() {
+            assert_eq!(bulk.pop_max(), one_by_one.pop_max());
+            assert_eq!(bulk.pop_min(), one_by_one.pop_min());
+        }
+        assert!(one_by_one.is_empty());
+    }
+
+    #[test]
+    fn heap_minmax_into_max_binary_heap() {
+        let items: Vec<usize> = (0..500).map(|i| (i * 37) % 101).collect();
+        let minmax = MinMax::build_heap(items.clone());
+        let max = *minmax.peek_max().unwrap();
+
+        let binary_heap = minmax.into_max_binary_heap();
+        assert_eq!(binary_heap.peek(), Some(&max));
+
+        let mut expected = items;
+        expected.sort();
+        assert_eq!(binary_heap.into_sorted_vec(), expected);
+
+        let empty: MinMax<usize> = MinMax::with_capacity(0);
+        assert!(empty.into_max_binary_heap().is_empty());
+    }
+
+    #[test]
+    fn heap_minmax_is_valid_1() {
+        let mut minmax: MinMax<usize> = MinMax::init();
+        assert!(minmax.is_valid());
+
+        for i in 0..1000 {
+            minmax.push((i * 37) % 1009);
+            assert!(minmax.is_valid());
+        }
+
+        for i in 0..300 {
+            match i % 4 {
+                0 => minmax.pop_min(),
+                1 => minmax.pop_max(),
+                2 => minmax.replace_min(i * 7),
+                _ => minmax.replace_max(i * 3),
+            };
+            assert!(minmax.is_valid());
+        }
+    }
+
+    #[test]
+    fn heap_minmax_is_valid_2() {
+        // min level items must not exceed their descendants
+        let minmax = MinMax {
+            tree: vec![5, 9, 8, 4],
+        };
+        assert!(!minmax.is_valid());
+
+        // max level items must not be exceeded by their descendants
+        let minmax = MinMax {
+            tree: vec![1, 9, 8, 3, 10],
+        };
+        assert!(!minmax.is_valid());
+
+        // leaf on a max level is still bounded by its min level parent
+        let minmax = MinMax { tree: vec![5, 4] };
+        assert!(!minmax.is_valid());
+
+        let minmax = MinMax {
+            tree: vec![1, 9, 8, 3, 4, 2],
+        };
+        assert!(minmax.is_valid());
+    }
+
+    #[test]
+    fn heap_minmax_nth_min_max() {
+        let items: Vec<usize> = (0..300).map(|i| (i * 37) % 101).collect();
+        let minmax = MinMax::build_heap(items.clone());
+
+        let mut sorted = items;
+        sorted.sort();
+
+        for n in (0..300).step_by(7).chain(vec![299]) {
+            assert_eq!(minmax.nth_min(n), Some(sorted[n]));
+            assert_eq!(minmax.nth_max(n), Some(sorted[299 - n]));
+        }
+        assert_eq!(minmax.nth_min(300), None);
+        assert_eq!(minmax.nth_max(300), None);
+
+        // heap is left untouched
+        assert_eq!(minmax.size(), 300);
+        assert!(minmax.is_valid());
+
+        let empty: MinMax<usize> = MinMax::init();
+        assert_eq!(empty.nth_min(0), None);
+        assert_eq!(empty.nth_max(0), None);
+    }
+
+    #[test]
+    fn heap_minmax_pop_while() {
+        let items: Vec<usize> = (0..100).map(|i| (i * 37) % 100).collect();
+        let mut minmax = MinMax::build_heap(items);
+
+        let expected: Vec<usize> = (0..30).collect();
+        assert_eq!(minmax.pop_min_while(|item| *item < 30), expected);
+        assert_eq!(minmax.size(), 70);
+        assert_eq!(minmax.peek_min_max(), Some((&30, &99)));
+        assert!(minmax.is_valid());
+
+        let expected: Vec<usize> = (90..100).rev().collect();
+        assert_eq!(minmax.pop_max_while(|item| *item >= 90), expected);
+        assert_eq!(minmax.size(), 60);
+        assert_eq!(minmax.peek_min_max(), Some((&30, &89)));
+        assert!(minmax.is_valid());
+
+        // nothing is popped if predicate fails on the first item
+        assert!(minmax.pop_min_while(|_| false).is_empty());
+        assert_eq!(minmax.size(), 60);
+
+        let expected: Vec<usize> = (30..90).collect();
+        assert_eq!(minmax.pop_min_while(|_| true), expected);
+        assert!(minmax.is_empty());
+        assert!(minmax.pop_max_while(|_| true).is_empty());
+    }
 }
@@ -4,4 +4,5 @@ mod minmax;
 
 pub use binomial::BinomialHeap;
 pub use fibonacci::FibonacciHeap;
+pub use minmax::Extremum;
 pub use minmax::MinMax;
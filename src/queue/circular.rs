@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 /// A circular buffer, circular queue, ring buffer is a data structure that uses a single, fixed-size buffer as if it were connected end-to-end.
 /// This structure lends itself easily to buffering data streams.
 ///
@@ -87,6 +89,21 @@ impl<T> Circular<T> {
         return (self.rear_index + 1) % self.capacity == self.front_index;
     }
 
+    /// Returns number of items that can be inserted before the queue is full
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(2);
+    /// assert_eq!(circular_buffer.remaining_capacity(), 2);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// assert_eq!(circular_buffer.remaining_capacity(), 1);
+    /// ```
+    pub fn remaining_capacity(&self) -> usize {
+        // one slot of the internal vector is always left empty to tell apart full and empty queues
+        self.capacity - 1 - self.size
+    }
+
     /// If queue is not full it will insert an element at the end of the queue.
     /// If queue is full, oldest item will be discarded and new item will be inserted at the end of the queue.
     ///
@@ -154,6 +171,48 @@ impl<T> Circular<T> {
         return Some(element);
     }
 
+    /// Returns a reference to the most recently enqueued item. returns None if queue is empty
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(2);
+    /// assert_eq!(circular_buffer.back(), None);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.enqueue(2);
+    /// assert_eq!(circular_buffer.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.rget(0)
+    }
+
+    /// Returns a reference to the item `n` positions before the rear of the queue. returns None if `n` >= size()
+    ///
+    /// `rget(0)` is the most recently enqueued item and `rget(size() - 1)` is the front of the queue
+    ///
+    /// # Arguments
+    /// * `n`: number of positions from the rear of the queue
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(3);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.enqueue(2);
+    /// circular_buffer.enqueue(3);
+    ///
+    /// assert_eq!(circular_buffer.rget(1), Some(&2));
+    /// assert_eq!(circular_buffer.rget(2), Some(&1));
+    /// assert_eq!(circular_buffer.rget(3), None);
+    /// ```
+    pub fn rget(&self, n: usize) -> Option<&T> {
+        if n >= self.size() {
+            return None;
+        }
+
+        Some(&self[self.size() - 1 - n])
+    }
+
     /// Transforms each element in the queue using the transform function provided
     ///
     /// # Arguments
@@ -209,6 +268,282 @@ impl<T> Circular<T> {
         }
     }
 
+    /// Returns an iterator over the items of the queue paired with their logical index.
+    /// Logical index 0 refers to the front of the queue and `size() - 1` refers to the rear of the queue
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(2);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.enqueue(2);
+    /// circular_buffer.enqueue(3);
+    ///
+    /// let items: Vec<(usize, &usize)> = circular_buffer.enumerate_logical().collect();
+    /// assert_eq!(items, vec![(0, &2), (1, &3)]);
+    /// ```
+    pub fn enumerate_logical(&self) -> impl Iterator<Item = (usize, &T)> {
+        (0..self.size()).map(move |index| (index, &self[index]))
+    }
+
+    /// Rotates the items of the queue `n` positions to the left.
+    /// After rotation, the item at logical index `n` becomes the front of the queue.
+    /// `n` wraps around the size of the queue, so rotating by `size()` leaves the queue unchanged
+    ///
+    /// # Arguments
+    /// * `n`: number of positions to rotate
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(3);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.enqueue(2);
+    /// circular_buffer.enqueue(3);
+    ///
+    /// circular_buffer.rotate_left(1);
+    ///
+    /// assert_eq!(circular_buffer[0], 2);
+    /// assert_eq!(circular_buffer[1], 3);
+    /// assert_eq!(circular_buffer[2], 1);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.empty() {
+            return;
+        }
+
+        let n = n % self.size();
+
+        // rotating by reversing both parts and then the whole queue only swaps items in place
+        self.reverse_logical(0, n);
+        self.reverse_logical(n, self.size());
+        self.reverse_logical(0, self.size());
+    }
+
+    /// Rotates the items of the queue `n` positions to the right.
+    /// After rotation, the item at logical index `size() - n` becomes the front of the queue.
+    /// `n` wraps around the size of the queue, so rotating by `size()` leaves the queue unchanged
+    ///
+    /// # Arguments
+    /// * `n`: number of positions to rotate
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(3);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.enqueue(2);
+    /// circular_buffer.enqueue(3);
+    ///
+    /// circular_buffer.rotate_right(1);
+    ///
+    /// assert_eq!(circular_buffer[0], 3);
+    /// assert_eq!(circular_buffer[1], 1);
+    /// assert_eq!(circular_buffer[2], 2);
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.empty() {
+            return;
+        }
+
+        let n = n % self.size();
+
+        self.rotate_left(self.size() - n);
+    }
+
+    /// Returns a pair of slices which contain the items of the queue in order.
+    /// The first slice starts at the front of the queue. The second slice is empty unless the items wrap around the end of the internal buffer
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(3);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.enqueue(2);
+    /// circular_buffer.enqueue(3);
+    /// assert_eq!(circular_buffer.as_slices(), (&[1, 2, 3][..], &[][..]));
+    ///
+    /// // items wrap around the end of the buffer
+    /// circular_buffer.enqueue(4);
+    /// circular_buffer.enqueue(5);
+    /// assert_eq!(circular_buffer.as_slices(), (&[3, 4][..], &[5][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let end = self.front_index + self.size;
+
+        if end <= self.capacity {
+            (&self.internal_vec[self.front_index..end], &[])
+        } else {
+            (
+                &self.internal_vec[self.front_index..],
+                &self.internal_vec[..end - self.capacity],
+            )
+        }
+    }
+
+    /// Creates a circular queue of the given `capacity` and fills it with items of `deque` from front to back.
+    /// If `deque` has more items than `capacity`, only the last `capacity` items are kept
+    ///
+    /// # Arguments
+    /// * `capacity`: capacity of the queue
+    /// * `deque`: items to fill the queue with
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque: VecDeque<usize> = (1..=4).collect();
+    /// let circular_buffer = rudac::queue::Circular::from_vecdeque(3, deque);
+    ///
+    /// assert_eq!(circular_buffer.size(), 3);
+    /// assert_eq!(circular_buffer[0], 2);
+    /// assert_eq!(circular_buffer[2], 4);
+    /// ```
+    pub fn from_vecdeque(capacity: usize, deque: VecDeque<T>) -> Circular<T> {
+        let mut circular = Circular::new(capacity);
+
+        for item in deque {
+            circular.enqueue(item);
+        }
+
+        circular
+    }
+
+    /// Consumes the queue and returns its items in a `VecDeque`, from front to back
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(2);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.enqueue(2);
+    /// circular_buffer.enqueue(3);
+    ///
+    /// let deque = circular_buffer.into_vecdeque();
+    /// assert_eq!(deque, vec![2, 3]);
+    /// ```
+    pub fn into_vecdeque(self) -> VecDeque<T> {
+        let mut items = self.internal_vec;
+
+        // bring the front of the queue to the start of the vector and drop the slots outside of the queue
+        items.rotate_left(self.front_index);
+        items.truncate(self.size);
+
+        VecDeque::from(items)
+    }
+
+    /// Swaps the items at logical indices `i` and `j`
+    ///
+    /// # Arguments
+    /// * `i`: logical index of the first item
+    /// * `j`: logical index of the second item
+    ///
+    /// # Panics
+    /// * panics if `i` or `j` is out of bounds: 0 <= index < size()
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(3);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.enqueue(2);
+    /// circular_buffer.enqueue(3);
+    ///
+    /// circular_buffer.swap(0, 2);
+    ///
+    /// assert_eq!(circular_buffer[0], 3);
+    /// assert_eq!(circular_buffer[2], 1);
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) {
+        if i >= self.size() || j >= self.size() {
+            panic!("index out of bounds");
+        }
+
+        let physical_i = (self.front_index + i) % self.capacity;
+        let physical_j = (self.front_index + j) % self.capacity;
+        self.internal_vec.swap(physical_i, physical_j);
+    }
+
+    // reverses the items between logical indices `start`(inclusive) and `end`(exclusive)
+    fn reverse_logical(&mut self, start: usize, end: usize) {
+        if end <= start {
+            return;
+        }
+
+        let (mut i, mut j) = (start, end - 1);
+        while i < j {
+            let physical_i = (self.front_index + i) % self.capacity;
+            let physical_j = (self.front_index + j) % self.capacity;
+            self.internal_vec.swap(physical_i, physical_j);
+
+            i += 1;
+            j -= 1;
+        }
+    }
+
+    /// Keeps only the items for which `f` returns true. Kept items stay in the same order from front to back
+    ///
+    /// # Arguments
+    /// * `f`: predicate which decides whether an item is kept
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(4);
+    ///
+    /// for i in 1..=4 {
+    ///     circular_buffer.enqueue(i);
+    /// }
+    ///
+    /// circular_buffer.retain(|item| item % 2 == 0);
+    ///
+    /// assert_eq!(circular_buffer.size(), 2);
+    /// assert_eq!(circular_buffer[0], 2);
+    /// assert_eq!(circular_buffer[1], 4);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        let mut items = std::mem::take(&mut self.internal_vec);
+
+        // items may wrap around the end of the internal buffer, so lay them out from the front before filtering
+        items.rotate_left(self.front_index);
+        items.truncate(self.size);
+        items.retain(f);
+
+        self.clear();
+        for item in items {
+            self.enqueue(item);
+        }
+    }
+
+    /// Fills the queue to its capacity with clones of `value`, discarding the current items.
+    /// Useful to seed moving window buffers with a baseline value
+    ///
+    /// # Arguments
+    /// * `value`: item that the queue is filled with
+    ///
+    /// # Examples
+    /// ```
+    /// let mut circular_buffer: rudac::queue::Circular<usize> = rudac::queue::Circular::new(3);
+    ///
+    /// circular_buffer.enqueue(1);
+    /// circular_buffer.fill(0);
+    ///
+    /// assert!(circular_buffer.full());
+    /// assert_eq!(circular_buffer[0], 0);
+    /// assert_eq!(circular_buffer[2], 0);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.clear();
+
+        // one slot of the internal vector is always left empty to tell apart full and empty queues
+        self.internal_vec.resize(self.capacity - 1, value);
+
+        self.rear_index = self.capacity - 1;
+        self.size = self.capacity - 1;
+    }
+
     /// Clears the queue and resets internal flags
     pub fn clear(&mut self) {
         self.internal_vec.clear();
@@ -244,6 +579,39 @@ impl<T> std::ops::IndexMut<usize> for Circular<T> {
     }
 }
 
+// capacity of a circular queue together with its items from front to back,
+// which is all that is needed to rebuild an equivalent queue
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CircularState<I> {
+    capacity: usize,
+    items: I,
+}
+
+/// Serializes the capacity and the items of the queue from front to back
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Circular<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let items: Vec<&T> = self.enumerate_logical().map(|(_, item)| item).collect();
+        let state = CircularState {
+            capacity: self.capacity - 1,
+            items,
+        };
+
+        state.serialize(serializer)
+    }
+}
+
+/// Rebuilds a queue of the serialized capacity holding the serialized items in the same order
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Circular<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state: CircularState<VecDeque<T>> = CircularState::deserialize(deserializer)?;
+
+        Ok(Circular::from_vecdeque(state.capacity, state.items))
+    }
+}
+
 pub struct CircularIterator<'a, T> {
     vec_circular: &'a Circular<T>,
     index: usize,
@@ -384,6 +752,41 @@ mod tests {
         assert_eq!(vc.size, 3);
     }
 
+    #[test]
+    fn remaining_capacity_on_fill_levels() {
+        let mut vc: Circular<usize> = Circular::new(3);
+
+        for i in 0..3 {
+            assert_eq!(vc.remaining_capacity(), 3 - i);
+            assert_eq!(vc.remaining_capacity() + vc.size(), 3);
+            assert!(!vc.full());
+            vc.enqueue(i);
+        }
+
+        assert_eq!(vc.remaining_capacity(), 0);
+        assert!(vc.full());
+
+        // enqueue on a full queue overwrites the oldest item
+        vc.enqueue(3);
+        assert_eq!(vc.remaining_capacity(), 0);
+        assert!(vc.full());
+
+        vc.dequeue();
+        assert_eq!(vc.remaining_capacity(), 1);
+        assert_eq!(vc.remaining_capacity() + vc.size(), 3);
+        assert!(!vc.full());
+    }
+
+    #[test]
+    fn remaining_capacity_on_capacity_zero() {
+        let mut vc: Circular<usize> = Circular::new(0);
+
+        assert_eq!(vc.remaining_capacity(), 0);
+
+        vc.enqueue(1);
+        assert_eq!(vc.remaining_capacity(), 0);
+    }
+
     #[test]
     fn dequeue_on_queue_capacity_zero() {
         let mut vc: Circular<String> = Circular::new(0);
@@ -669,6 +1072,316 @@ mod tests {
         }
     }
 
+    #[test]
+    fn enumerate_logical_rear_before_front() {
+        let mut vc: Circular<String> = Circular::new(3);
+
+        vc.enqueue(String::from("element1"));
+        vc.enqueue(String::from("element2"));
+        vc.enqueue(String::from("element3"));
+        vc.enqueue(String::from("element4"));
+        vc.enqueue(String::from("element5"));
+
+        let template = ["element3", "element4", "element5"];
+        let mut expected_index = 0;
+
+        for (index, item) in vc.enumerate_logical() {
+            assert_eq!(index, expected_index);
+            assert_eq!(item, template[index]);
+            expected_index += 1;
+        }
+
+        assert_eq!(expected_index, vc.size());
+    }
+
+    #[test]
+    fn enumerate_logical_on_empty_queue() {
+        let vc: Circular<String> = Circular::new(2);
+
+        assert_eq!(vc.enumerate_logical().count(), 0);
+    }
+
+    #[test]
+    fn rotate_left_full_queue() {
+        let mut vc: Circular<usize> = Circular::new(5);
+
+        // overflow the queue so rear is before front
+        for i in 0..7 {
+            vc.enqueue(i);
+        }
+
+        for n in 0..12 {
+            let mut rotated: Circular<usize> = Circular::new(5);
+            for i in 0..7 {
+                rotated.enqueue(i);
+            }
+
+            rotated.rotate_left(n);
+
+            assert!(rotated.full());
+            for i in 0..5 {
+                assert_eq!(rotated[i], vc[(i + n) % 5]);
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_right_full_queue() {
+        let mut vc: Circular<usize> = Circular::new(5);
+
+        for i in 0..7 {
+            vc.enqueue(i);
+        }
+
+        vc.rotate_right(2);
+
+        let template = [5, 6, 2, 3, 4];
+        for (index, item) in vc.enumerate_logical() {
+            assert_eq!(*item, template[index]);
+        }
+
+        match vc.dequeue() {
+            Some(data) => assert_eq!(*data, 5),
+            None => panic!("Data must not be None"),
+        }
+
+        vc.enqueue(7);
+        assert_eq!(vc[3], 4);
+        assert_eq!(vc[4], 7);
+    }
+
+    #[test]
+    fn rotate_on_empty_queue() {
+        let mut vc: Circular<usize> = Circular::new(3);
+
+        vc.rotate_left(2);
+        vc.rotate_right(2);
+
+        assert!(vc.empty());
+    }
+
+    #[test]
+    fn as_slices_not_wrapped() {
+        let mut vc: Circular<usize> = Circular::new(5);
+        assert_eq!(vc.as_slices(), (&[][..], &[][..]));
+
+        vc.enqueue(1);
+        vc.enqueue(2);
+        vc.enqueue(3);
+        vc.dequeue();
+
+        let (head, tail) = vc.as_slices();
+        assert_eq!(head, &[2, 3]);
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    fn as_slices_wrapped() {
+        let mut vc: Circular<usize> = Circular::new(4);
+
+        for i in 0..8 {
+            vc.enqueue(i);
+
+            let (head, tail) = vc.as_slices();
+            let items: Vec<usize> = head.iter().chain(tail.iter()).copied().collect();
+            let expected: Vec<usize> = vc.enumerate_logical().map(|(_, item)| *item).collect();
+            assert_eq!(items, expected);
+        }
+
+        // front of the queue is near the end of the buffer
+        let (head, tail) = vc.as_slices();
+        assert_eq!(head, &[4]);
+        assert_eq!(tail, &[5, 6, 7]);
+
+        vc.dequeue();
+        vc.dequeue();
+        assert_eq!(vc.as_slices(), (&[6, 7][..], &[][..]));
+
+        let mut copied = [0; 2];
+        copied.copy_from_slice(vc.as_slices().0);
+        assert_eq!(copied, [6, 7]);
+    }
+
+    #[test]
+    fn swap_wrapped() {
+        let mut vc: Circular<usize> = Circular::new(4);
+
+        for i in 0..6 {
+            vc.enqueue(i);
+        }
+
+        // items wrap around the end of the internal buffer
+        assert!(!vc.as_slices().1.is_empty());
+
+        vc.swap(0, vc.size() - 1);
+        assert_eq!(vc.as_slices(), (&[5, 3, 4][..], &[2][..]));
+
+        vc.swap(1, 1);
+        vc.swap(2, 1);
+        let items: Vec<usize> = vc.enumerate_logical().map(|(_, item)| *item).collect();
+        assert_eq!(items, vec![5, 4, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn swap_out_of_bounds() {
+        let mut vc: Circular<usize> = Circular::new(4);
+        vc.enqueue(1);
+        vc.enqueue(2);
+
+        vc.swap(0, 2);
+    }
+
+    #[test]
+    fn vecdeque_round_trip_wrapped() {
+        let mut vc: Circular<usize> = Circular::new(4);
+
+        for i in 0..7 {
+            vc.enqueue(i);
+        }
+        vc.dequeue();
+
+        // items wrap around the end of the internal buffer
+        assert!(!vc.as_slices().1.is_empty());
+
+        let deque = vc.into_vecdeque();
+        assert_eq!(deque, vec![4, 5, 6]);
+
+        let mut vc = Circular::from_vecdeque(4, deque);
+        assert_eq!(vc.size(), 3);
+        vc.enqueue(7);
+        vc.enqueue(8);
+
+        let items: Vec<usize> = vc.enumerate_logical().map(|(_, item)| *item).collect();
+        assert_eq!(items, vec![5, 6, 7, 8]);
+        assert_eq!(vc.into_vecdeque(), vec![5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn vecdeque_conversions() {
+        let vc: Circular<usize> = Circular::from_vecdeque(3, (0..10).collect());
+        assert_eq!(vc.into_vecdeque(), vec![7, 8, 9]);
+
+        let vc: Circular<usize> = Circular::from_vecdeque(3, VecDeque::new());
+        assert!(vc.empty());
+        assert!(vc.into_vecdeque().is_empty());
+
+        let mut vc: Circular<usize> = Circular::new(3);
+        vc.enqueue(1);
+        vc.enqueue(2);
+        vc.dequeue();
+        assert_eq!(vc.into_vecdeque(), vec![2]);
+    }
+
+    #[test]
+    fn fill_capacity_4() {
+        let mut vc: Circular<usize> = Circular::new(4);
+
+        // wrap the items around the end of the internal buffer before filling
+        for i in 0..6 {
+            vc.enqueue(i);
+        }
+        vc.fill(7);
+
+        assert!(vc.full());
+        assert_eq!(vc.size(), 4);
+        assert!(vc.enumerate_logical().all(|(_, item)| *item == 7));
+
+        // oldest baseline values are discarded as new items arrive
+        vc.enqueue(1);
+        vc.enqueue(2);
+        let items: Vec<usize> = vc.enumerate_logical().map(|(_, item)| *item).collect();
+        assert_eq!(items, vec![7, 7, 1, 2]);
+
+        let mut vc: Circular<usize> = Circular::new(0);
+        vc.fill(7);
+        assert_eq!(vc.size(), 0);
+    }
+
+    #[test]
+    fn retain_wrapped() {
+        let mut vc: Circular<usize> = Circular::new(5);
+
+        for i in 0..8 {
+            vc.enqueue(i);
+        }
+        vc.dequeue();
+
+        // items wrap around the end of the internal buffer
+        assert!(!vc.as_slices().1.is_empty());
+
+        vc.retain(|item| item % 2 == 0);
+
+        assert_eq!(vc.size(), 2);
+        let items: Vec<usize> = vc.enumerate_logical().map(|(_, item)| *item).collect();
+        assert_eq!(items, vec![4, 6]);
+
+        // queue keeps working as a fifo after compaction
+        for i in 10..14 {
+            vc.enqueue(i);
+        }
+        let items: Vec<usize> = vc.enumerate_logical().map(|(_, item)| *item).collect();
+        assert_eq!(items, vec![6, 10, 11, 12, 13]);
+        assert!(vc.full());
+
+        vc.retain(|_| false);
+        assert!(vc.empty());
+        assert_eq!(vc.dequeue(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_wrapped() {
+        let mut vc: Circular<usize> = Circular::new(4);
+
+        for i in 0..7 {
+            vc.enqueue(i);
+        }
+        vc.dequeue();
+
+        // items wrap around the end of the internal buffer
+        assert!(!vc.as_slices().1.is_empty());
+
+        let json = serde_json::to_string(&vc).unwrap();
+        assert_eq!(json, r#"{"capacity":4,"items":[4,5,6]}"#);
+
+        let mut deserialized: Circular<usize> = serde_json::from_str(&json).unwrap();
+        assert!(deserialized.enumerate_logical().eq(vc.enumerate_logical()));
+        assert_eq!(deserialized.size(), 3);
+        assert_eq!(deserialized.remaining_capacity(), 1);
+
+        deserialized.enqueue(7);
+        deserialized.enqueue(8);
+        let json = serde_json::to_string(&deserialized).unwrap();
+        assert_eq!(json, r#"{"capacity":4,"items":[5,6,7,8]}"#);
+    }
+
+    #[test]
+    fn back_and_rget_wrapped() {
+        let mut vc: Circular<usize> = Circular::new(4);
+        assert_eq!(vc.back(), None);
+        assert_eq!(vc.rget(0), None);
+
+        for i in 0..7 {
+            vc.enqueue(i);
+
+            assert_eq!(vc.back(), Some(&i));
+        }
+        vc.dequeue();
+
+        // items wrap around the end of the internal buffer
+        assert!(!vc.as_slices().1.is_empty());
+
+        assert_eq!(vc.back(), Some(&6));
+        assert_eq!(vc.rget(1), Some(&5));
+        assert_eq!(vc.rget(2), Some(&4));
+        assert_eq!(vc.rget(3), None);
+
+        for n in 0..vc.size() {
+            assert_eq!(vc.rget(n), Some(&vc[vc.size() - 1 - n]));
+        }
+    }
+
     fn all_caps(text: &String) -> String {
         return text.to_uppercase();
     }
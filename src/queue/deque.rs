@@ -0,0 +1,381 @@
+/// A double-ended queue backed by a growable ring buffer.
+/// Items can be pushed and popped from both ends in amortized constant time.
+/// Unlike `Circular`, the deque never discards items and grows when it runs out of room
+///
+/// # Examples
+/// ```
+/// use rudac::queue::Deque;
+///
+/// let mut deque: Deque<usize> = Deque::init();
+///
+/// deque.push_back(1);
+/// deque.push_back(2);
+/// deque.push_front(0);
+///
+/// assert_eq!(deque.len(), 3);
+/// assert_eq!(deque.pop_front(), Some(0));
+/// assert_eq!(deque.pop_back(), Some(2));
+/// assert_eq!(deque.pop_back(), Some(1));
+/// assert_eq!(deque.pop_back(), None);
+/// ```
+#[derive(Debug)]
+pub struct Deque<T> {
+    // ring buffer holding the items. empty slots are None
+    buffer: Vec<Option<T>>,
+
+    // physical index of the front item
+    head: usize,
+
+    // number of items in the deque
+    len: usize,
+}
+
+impl<T> Deque<T> {
+    /// Initializes an empty deque with zero capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::Deque;
+    ///
+    /// let deque: Deque<usize> = Deque::init();
+    ///
+    /// assert_eq!(deque.capacity(), 0);
+    /// ```
+    pub fn init() -> Deque<T> {
+        Deque::with_capacity(0)
+    }
+
+    /// Initializes an empty deque with room for `capacity` items before it has to grow
+    ///
+    /// # Arguments
+    /// * `capacity`: initial capacity of the deque
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::Deque;
+    ///
+    /// let deque: Deque<usize> = Deque::with_capacity(16);
+    ///
+    /// assert_eq!(deque.capacity(), 16);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Deque<T> {
+        Deque {
+            buffer: Deque::empty_buffer(capacity),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Pushes `item` at the front of the deque. grows the deque if it is full
+    ///
+    /// # Arguments
+    /// * `item`: data to be pushed at the front
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::Deque;
+    ///
+    /// let mut deque: Deque<usize> = Deque::init();
+    ///
+    /// deque.push_front(1);
+    /// deque.push_front(0);
+    ///
+    /// assert_eq!(deque.front(), Some(&0));
+    /// assert_eq!(deque.back(), Some(&1));
+    /// ```
+    pub fn push_front(&mut self, item: T) {
+        if self.is_full() {
+            self.grow();
+        }
+
+        // step head back by one, wrapping around the start of the buffer
+        self.head = (self.head + self.capacity() - 1) % self.capacity();
+        self.buffer[self.head] = Some(item);
+
+        self.len += 1;
+    }
+
+    /// Pushes `item` at the back of the deque. grows the deque if it is full
+    ///
+    /// # Arguments
+    /// * `item`: data to be pushed at the back
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::Deque;
+    ///
+    /// let mut deque: Deque<usize> = Deque::init();
+    ///
+    /// deque.push_back(0);
+    /// deque.push_back(1);
+    ///
+    /// assert_eq!(deque.front(), Some(&0));
+    /// assert_eq!(deque.back(), Some(&1));
+    /// ```
+    pub fn push_back(&mut self, item: T) {
+        if self.is_full() {
+            self.grow();
+        }
+
+        let index = self.physical_index(self.len);
+        self.buffer[index] = Some(item);
+
+        self.len += 1;
+    }
+
+    /// Removes the item at the front of the deque and returns it. returns None if deque is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::Deque;
+    ///
+    /// let mut deque: Deque<usize> = Deque::init();
+    ///
+    /// deque.push_back(0);
+    /// deque.push_back(1);
+    ///
+    /// assert_eq!(deque.pop_front(), Some(0));
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let item = self.buffer[self.head].take();
+
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+
+        item
+    }
+
+    /// Removes the item at the back of the deque and returns it. returns None if deque is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::Deque;
+    ///
+    /// let mut deque: Deque<usize> = Deque::init();
+    ///
+    /// deque.push_back(0);
+    /// deque.push_back(1);
+    ///
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), Some(0));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let index = self.physical_index(self.len - 1);
+        self.len -= 1;
+
+        self.buffer[index].take()
+    }
+
+    /// Returns a reference to the item at the front of the deque. returns None if deque is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::Deque;
+    ///
+    /// let mut deque: Deque<usize> = Deque::init();
+    /// assert_eq!(deque.front(), None);
+    ///
+    /// deque.push_back(0);
+    /// assert_eq!(deque.front(), Some(&0));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.buffer[self.head].as_ref()
+    }
+
+    /// Returns a reference to the item at the back of the deque. returns None if deque is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::Deque;
+    ///
+    /// let mut deque: Deque<usize> = Deque::init();
+    /// assert_eq!(deque.back(), None);
+    ///
+    /// deque.push_front(0);
+    /// assert_eq!(deque.back(), Some(&0));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.buffer[self.physical_index(self.len - 1)].as_ref()
+    }
+
+    /// Returns number of items in the deque
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if deque is empty, false otherwise
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns number of items the deque can hold before it has to grow
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Removes all items from the deque. capacity will stay the same
+    pub fn clear(&mut self) {
+        for slot in self.buffer.iter_mut() {
+            *slot = None;
+        }
+
+        self.head = 0;
+        self.len = 0;
+    }
+
+    // maps logical `index` (0 is the front of the deque) to its index in the buffer
+    fn physical_index(&self, index: usize) -> usize {
+        (self.head + index) % self.capacity()
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    // doubles the capacity of the buffer and moves the items to the start of the new buffer
+    fn grow(&mut self) {
+        let new_capacity = std::cmp::max(self.capacity() * 2, 1);
+        let mut new_buffer = Deque::empty_buffer(new_capacity);
+
+        for (index, slot) in new_buffer.iter_mut().enumerate().take(self.len) {
+            let old_index = self.physical_index(index);
+            *slot = self.buffer[old_index].take();
+        }
+
+        self.buffer = new_buffer;
+        self.head = 0;
+    }
+
+    fn empty_buffer(capacity: usize) -> Vec<Option<T>> {
+        let mut buffer = Vec::with_capacity(capacity);
+        buffer.resize_with(capacity, || None);
+
+        buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_deque_init() {
+        let deque: Deque<usize> = Deque::init();
+
+        assert_eq!(deque.len(), 0);
+        assert_eq!(deque.capacity(), 0);
+        assert!(deque.is_empty());
+        assert_eq!(deque.front(), None);
+        assert_eq!(deque.back(), None);
+    }
+
+    #[test]
+    fn queue_deque_fifo() {
+        let mut deque: Deque<usize> = Deque::init();
+
+        for i in 0..100 {
+            deque.push_back(i);
+            assert_eq!(deque.back(), Some(&i));
+        }
+
+        assert_eq!(deque.len(), 100);
+        assert_eq!(deque.capacity(), 128);
+
+        for i in 0..100 {
+            assert_eq!(deque.front(), Some(&i));
+            assert_eq!(deque.pop_front(), Some(i));
+        }
+
+        assert!(deque.is_empty());
+        assert_eq!(deque.pop_front(), None);
+    }
+
+    #[test]
+    fn queue_deque_lifo() {
+        let mut deque: Deque<usize> = Deque::init();
+
+        for i in 0..100 {
+            deque.push_front(i);
+            assert_eq!(deque.front(), Some(&i));
+        }
+
+        for i in 0..100 {
+            assert_eq!(deque.pop_front(), Some(99 - i));
+        }
+
+        for i in 0..100 {
+            deque.push_back(i);
+        }
+
+        for i in (0..100).rev() {
+            assert_eq!(deque.back(), Some(&i));
+            assert_eq!(deque.pop_back(), Some(i));
+        }
+
+        assert_eq!(deque.pop_back(), None);
+    }
+
+    #[test]
+    fn queue_deque_grow_when_wrapped() {
+        let mut deque: Deque<usize> = Deque::with_capacity(4);
+
+        // move head to the end of the buffer so items wrap around
+        deque.push_back(0);
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.pop_front();
+        deque.pop_front();
+        deque.push_back(3);
+        deque.push_back(4);
+        deque.push_front(1);
+        assert_eq!(deque.capacity(), 4);
+
+        // deque is full, both of these pushes must grow it while keeping the order
+        deque.push_front(0);
+        deque.push_back(5);
+        assert_eq!(deque.capacity(), 8);
+        assert_eq!(deque.len(), 6);
+
+        for i in 0..3 {
+            assert_eq!(deque.pop_front(), Some(i));
+            assert_eq!(deque.pop_back(), Some(5 - i));
+        }
+
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn queue_deque_clear() {
+        let mut deque: Deque<String> = Deque::init();
+
+        deque.push_back(String::from("element1"));
+        deque.push_front(String::from("element2"));
+        deque.clear();
+
+        assert!(deque.is_empty());
+        assert_eq!(deque.capacity(), 2);
+        assert_eq!(deque.front(), None);
+
+        deque.push_front(String::from("element3"));
+        assert_eq!(deque.back(), Some(&String::from("element3")));
+    }
+}
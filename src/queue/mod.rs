@@ -1,3 +1,11 @@
 mod circular;
+mod deque;
+mod moving_max;
+mod priority_queue;
+mod timed_ring;
 
 pub use circular::Circular;
+pub use deque::Deque;
+pub use moving_max::MovingMax;
+pub use priority_queue::PriorityQueue;
+pub use timed_ring::TimedRing;
@@ -0,0 +1,240 @@
+use crate::queue::{Circular, Deque};
+
+/// Keeps the maximum of the last `window` items pushed into it.
+/// Items are buffered in a `Circular` queue which discards the oldest item once the window is full,
+/// while a monotonic `Deque` keeps the candidates for the maximum so it can be read in constant time.
+///
+/// # Examples
+/// ```
+/// use rudac::queue::MovingMax;
+///
+/// let mut moving_max: MovingMax<usize> = MovingMax::new(3);
+///
+/// moving_max.push(4);
+/// moving_max.push(2);
+/// moving_max.push(1);
+/// assert_eq!(moving_max.max(), Some(&4));
+///
+/// // 4 falls out of the window
+/// moving_max.push(3);
+/// assert_eq!(moving_max.max(), Some(&3));
+/// ```
+#[derive(Debug)]
+pub struct MovingMax<T: Ord + Clone> {
+    // items currently in the window, from oldest to newest
+    window: Circular<T>,
+
+    // candidates for the maximum paired with their sequence number.
+    // values are strictly decreasing from front to back, so the front is always the maximum
+    candidates: Deque<(usize, T)>,
+
+    // number of items pushed so far, used as sequence number of the next item
+    pushed: usize,
+
+    // maximum number of items in the window
+    window_size: usize,
+}
+
+impl<T: Ord + Clone> MovingMax<T> {
+    /// Creates a new moving maximum over the last `window_size` items
+    ///
+    /// # Arguments
+    /// * `window_size`: number of most recent items the maximum is computed over
+    ///
+    /// # Panics
+    /// * panics if `window_size` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::MovingMax;
+    ///
+    /// let moving_max: MovingMax<usize> = MovingMax::new(3);
+    ///
+    /// assert_eq!(moving_max.window_size(), 3);
+    /// ```
+    pub fn new(window_size: usize) -> MovingMax<T> {
+        if window_size == 0 {
+            panic!("Window size must be greater than zero");
+        }
+
+        MovingMax {
+            window: Circular::new(window_size),
+            candidates: Deque::with_capacity(window_size),
+            pushed: 0,
+            window_size,
+        }
+    }
+
+    /// Pushes `item` into the window. if window is full, the oldest item will be discarded
+    /// * Complexity: amortized O(1)
+    ///
+    /// # Arguments
+    /// * `item`: data to be pushed into the window
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::MovingMax;
+    ///
+    /// let mut moving_max: MovingMax<usize> = MovingMax::new(2);
+    ///
+    /// moving_max.push(1);
+    /// moving_max.push(2);
+    /// moving_max.push(3);
+    ///
+    /// assert_eq!(moving_max.size(), 2);
+    /// ```
+    pub fn push(&mut self, item: T) {
+        let sequence = self.pushed;
+        self.pushed += 1;
+
+        // drop the candidate that falls out of the window
+        if let Some((oldest, _)) = self.candidates.front() {
+            if *oldest + self.window_size <= sequence {
+                self.candidates.pop_front();
+            }
+        }
+
+        // items smaller than or equal to `item` can never be the maximum while `item` is in the window
+        while let Some((_, candidate)) = self.candidates.back() {
+            if *candidate > item {
+                break;
+            }
+            self.candidates.pop_back();
+        }
+
+        self.candidates.push_back((sequence, item.clone()));
+        self.window.enqueue(item);
+    }
+
+    /// Returns a reference to the maximum item in the window. returns None if no item is pushed yet
+    /// * Complexity: O(1)
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::MovingMax;
+    ///
+    /// let mut moving_max: MovingMax<usize> = MovingMax::new(2);
+    /// assert_eq!(moving_max.max(), None);
+    ///
+    /// moving_max.push(5);
+    /// moving_max.push(1);
+    /// assert_eq!(moving_max.max(), Some(&5));
+    ///
+    /// moving_max.push(2);
+    /// assert_eq!(moving_max.max(), Some(&2));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        match self.candidates.front() {
+            Some((_, item)) => Some(item),
+            None => None,
+        }
+    }
+
+    /// Returns number of items in the window
+    pub fn size(&self) -> usize {
+        self.window.size()
+    }
+
+    /// Returns maximum number of items in the window
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Returns an iterator over the items in the window, from oldest to newest
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::MovingMax;
+    ///
+    /// let mut moving_max: MovingMax<usize> = MovingMax::new(2);
+    ///
+    /// moving_max.push(1);
+    /// moving_max.push(2);
+    /// moving_max.push(3);
+    ///
+    /// assert!(moving_max.window().eq([2, 3].iter()));
+    /// ```
+    pub fn window(&self) -> impl Iterator<Item = &T> {
+        self.window.enumerate_logical().map(|(_, item)| item)
+    }
+
+    /// Removes all items from the window
+    pub fn clear(&mut self) {
+        self.window.clear();
+        self.candidates.clear();
+        self.pushed = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_max(items: &[usize], window_size: usize) -> Vec<usize> {
+        (0..items.len())
+            .map(|i| {
+                let start = (i + 1).saturating_sub(window_size);
+                *items[start..=i].iter().max().unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn queue_moving_max_1() {
+        let items: Vec<usize> = (0..200).map(|i| (i * 37) % 23).collect();
+        let expected = brute_force_max(&items, 3);
+
+        let mut moving_max: MovingMax<usize> = MovingMax::new(3);
+        assert_eq!(moving_max.max(), None);
+
+        for (item, max) in items.iter().zip(expected.iter()) {
+            moving_max.push(*item);
+
+            assert_eq!(moving_max.max(), Some(max));
+        }
+
+        assert_eq!(moving_max.size(), 3);
+        assert!(moving_max.window().eq(items[197..].iter()));
+    }
+
+    #[test]
+    fn queue_moving_max_2() {
+        let increasing: Vec<usize> = (0..20).collect();
+        let decreasing: Vec<usize> = (0..20).rev().collect();
+        let constant = [7; 20];
+
+        for items in [&increasing[..], &decreasing[..], &constant[..]].iter() {
+            for window_size in 1..5 {
+                let expected = brute_force_max(items, window_size);
+                let mut moving_max: MovingMax<usize> = MovingMax::new(window_size);
+
+                for (item, max) in items.iter().zip(expected.iter()) {
+                    moving_max.push(*item);
+
+                    assert_eq!(moving_max.max(), Some(max));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn queue_moving_max_clear() {
+        let mut moving_max: MovingMax<usize> = MovingMax::new(3);
+
+        moving_max.push(10);
+        moving_max.push(5);
+        moving_max.clear();
+
+        assert_eq!(moving_max.size(), 0);
+        assert_eq!(moving_max.max(), None);
+
+        moving_max.push(1);
+        assert_eq!(moving_max.max(), Some(&1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size must be greater than zero")]
+    fn queue_moving_max_panic_new() {
+        let _: MovingMax<usize> = MovingMax::new(0);
+    }
+}
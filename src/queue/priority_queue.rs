@@ -0,0 +1,250 @@
+use crate::heap::BinomialHeap;
+use std::cmp::Ordering;
+
+// item of the queue together with its priority and the order it was pushed in
+#[derive(Debug, Clone)]
+struct Entry<P: Ord, T> {
+    priority: P,
+    sequence: usize,
+    item: T,
+}
+
+// entries are stored in a min heap, so the entry with highest priority must be the smallest.
+// among entries with equal priorities the one pushed first is the smallest
+impl<P: Ord, T> Ord for Entry<P, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.sequence.cmp(&other.sequence))
+    }
+}
+
+impl<P: Ord, T> PartialOrd for Entry<P, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Ord, T> PartialEq for Entry<P, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<P: Ord, T> Eq for Entry<P, T> {}
+
+/// A priority queue that pairs every item with a priority and pops the item with highest priority first.
+/// Items with equal priorities are popped in the order they were pushed(FIFO).
+/// Items are stored in a `BinomialHeap` so neither items nor priorities need to be cloned
+///
+/// # Examples
+/// ```
+/// use rudac::queue::PriorityQueue;
+/// use std::cmp::Reverse;
+///
+/// let mut priority_queue = PriorityQueue::new();
+///
+/// priority_queue.push(1, "low");
+/// priority_queue.push(5, "first high");
+/// priority_queue.push(5, "second high");
+///
+/// assert_eq!(priority_queue.pop(), Some("first high"));
+/// assert_eq!(priority_queue.pop(), Some("second high"));
+/// assert_eq!(priority_queue.pop(), Some("low"));
+/// assert_eq!(priority_queue.pop(), None);
+///
+/// // wrap priorities in Reverse to pop the item with lowest priority first
+/// let mut priority_queue = PriorityQueue::new();
+///
+/// priority_queue.push(Reverse(5), "later");
+/// priority_queue.push(Reverse(1), "sooner");
+///
+/// assert_eq!(priority_queue.pop(), Some("sooner"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PriorityQueue<P: Ord, T> {
+    heap: BinomialHeap<Entry<P, T>>,
+
+    // number of items pushed so far, used as sequence number of the next item
+    pushed: usize,
+}
+
+impl<P: Ord, T> PriorityQueue<P, T> {
+    /// Creates an empty priority queue
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::PriorityQueue;
+    ///
+    /// let priority_queue: PriorityQueue<usize, String> = PriorityQueue::new();
+    ///
+    /// assert!(priority_queue.is_empty());
+    /// ```
+    pub fn new() -> PriorityQueue<P, T> {
+        PriorityQueue {
+            heap: BinomialHeap::default(),
+            pushed: 0,
+        }
+    }
+
+    /// Pushes `item` into the queue with the specified `priority`
+    /// * Complexity: amortized O(1)
+    ///
+    /// # Arguments
+    /// * `priority`: priority of the item
+    /// * `item`: data to be pushed into the queue
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::PriorityQueue;
+    ///
+    /// let mut priority_queue = PriorityQueue::new();
+    ///
+    /// priority_queue.push(2, 'a');
+    /// priority_queue.push(3, 'b');
+    ///
+    /// assert_eq!(priority_queue.size(), 2);
+    /// assert_eq!(priority_queue.peek(), Some(&'b'));
+    /// ```
+    pub fn push(&mut self, priority: P, item: T) {
+        let sequence = self.pushed;
+        self.pushed += 1;
+
+        self.heap.push(Entry {
+            priority,
+            sequence,
+            item,
+        });
+    }
+
+    /// Pops and returns the item with highest priority. Among items with highest priority, the one pushed first is returned.
+    /// Returns `None` if queue is empty
+    /// * Complexity: O(log(n))
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::PriorityQueue;
+    ///
+    /// let mut priority_queue = PriorityQueue::new();
+    ///
+    /// priority_queue.push(1, 'a');
+    /// priority_queue.push(1, 'b');
+    ///
+    /// assert_eq!(priority_queue.pop(), Some('a'));
+    /// assert_eq!(priority_queue.pop(), Some('b'));
+    /// assert_eq!(priority_queue.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        match self.heap.pop() {
+            Some(entry) => Some(entry.item),
+            None => None,
+        }
+    }
+
+    /// Returns a reference to the item that would be popped next. Returns `None` if queue is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::PriorityQueue;
+    ///
+    /// let mut priority_queue = PriorityQueue::new();
+    /// assert_eq!(priority_queue.peek(), None);
+    ///
+    /// priority_queue.push(1, 'a');
+    /// priority_queue.push(2, 'b');
+    ///
+    /// assert_eq!(priority_queue.peek(), Some(&'b'));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        match self.heap.peek() {
+            Some(entry) => Some(&entry.item),
+            None => None,
+        }
+    }
+
+    /// Returns number of items in the queue
+    pub fn size(&self) -> usize {
+        self.heap.size()
+    }
+
+    /// Returns `true` if queue is empty and `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Removes all items from the queue
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.pushed = 0;
+    }
+}
+
+impl<P: Ord, T> Default for PriorityQueue<P, T> {
+    fn default() -> PriorityQueue<P, T> {
+        PriorityQueue::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_priority_queue_fifo() {
+        let mut priority_queue = PriorityQueue::new();
+
+        for i in 0..100 {
+            priority_queue.push(0, i);
+        }
+
+        for i in 0..100 {
+            assert_eq!(priority_queue.pop(), Some(i));
+        }
+        assert_eq!(priority_queue.pop(), None);
+    }
+
+    #[test]
+    fn queue_priority_queue_priorities() {
+        let mut priority_queue = PriorityQueue::new();
+
+        // items are (priority, order of the item among items with the same priority)
+        let mut pushed = [0; 7];
+        for i in 0..140 {
+            let priority = (i * 3) % 7;
+            priority_queue.push(priority, (priority, pushed[priority]));
+            pushed[priority] += 1;
+        }
+        assert_eq!(priority_queue.size(), 140);
+
+        for priority in (0..7).rev() {
+            for order in 0..20 {
+                assert_eq!(priority_queue.peek(), Some(&(priority, order)));
+                assert_eq!(priority_queue.pop(), Some((priority, order)));
+            }
+        }
+        assert!(priority_queue.is_empty());
+    }
+
+    #[test]
+    fn queue_priority_queue_interleaved() {
+        let mut priority_queue = PriorityQueue::new();
+
+        priority_queue.push(1, "a");
+        priority_queue.push(2, "b");
+        priority_queue.push(1, "c");
+        assert_eq!(priority_queue.pop(), Some("b"));
+
+        priority_queue.push(1, "d");
+        priority_queue.push(3, "e");
+        assert_eq!(priority_queue.pop(), Some("e"));
+        assert_eq!(priority_queue.pop(), Some("a"));
+        assert_eq!(priority_queue.pop(), Some("c"));
+        assert_eq!(priority_queue.pop(), Some("d"));
+
+        priority_queue.push(1, "f");
+        priority_queue.clear();
+        assert_eq!(priority_queue.size(), 0);
+        assert_eq!(priority_queue.pop(), None);
+    }
+}
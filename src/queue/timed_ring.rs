@@ -0,0 +1,215 @@
+use crate::algo::search::partition_point;
+use crate::queue::Circular;
+
+/// Keeps the last `capacity` values pushed into it, each stamped with the tick it was pushed at.
+/// Values are buffered in a `Circular` queue which discards the oldest value once the ring is full.
+/// Since ticks are non-decreasing, number of values pushed since a given tick is found by a binary search,
+/// which is the core of a sliding window rate limiter.
+///
+/// # Examples
+/// ```
+/// use rudac::queue::TimedRing;
+///
+/// // allow at most 3 requests in any 10 ticks
+/// let mut timed_ring: TimedRing<&str> = TimedRing::new(3);
+///
+/// timed_ring.push(1, "a");
+/// timed_ring.push(4, "b");
+/// timed_ring.push(9, "c");
+/// assert_eq!(timed_ring.count_since(12 - 10), 2);
+///
+/// timed_ring.push(12, "d");
+/// assert_eq!(timed_ring.count_since(13 - 10), 3);
+/// ```
+#[derive(Debug)]
+pub struct TimedRing<T> {
+    // values currently in the ring paired with their ticks, from oldest to newest
+    entries: Circular<(u64, T)>,
+
+    // maximum number of values in the ring
+    capacity: usize,
+}
+
+impl<T> TimedRing<T> {
+    /// Creates a new timed ring which keeps the last `capacity` values
+    ///
+    /// # Arguments
+    /// * `capacity`: number of most recent values kept in the ring
+    ///
+    /// # Panics
+    /// * panics if `capacity` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::TimedRing;
+    ///
+    /// let timed_ring: TimedRing<usize> = TimedRing::new(3);
+    ///
+    /// assert_eq!(timed_ring.capacity(), 3);
+    /// ```
+    pub fn new(capacity: usize) -> TimedRing<T> {
+        if capacity == 0 {
+            panic!("Capacity must be greater than zero");
+        }
+
+        TimedRing {
+            entries: Circular::new(capacity),
+            capacity,
+        }
+    }
+
+    /// Pushes `value` stamped with `tick` into the ring. if ring is full, the oldest value will be discarded
+    /// * Complexity: O(1)
+    ///
+    /// # Arguments
+    /// * `tick`: time at which `value` is pushed
+    /// * `value`: data to be pushed into the ring
+    ///
+    /// # Panics
+    /// * panics if `tick` is less than tick of the most recently pushed value
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::TimedRing;
+    ///
+    /// let mut timed_ring: TimedRing<usize> = TimedRing::new(2);
+    ///
+    /// timed_ring.push(1, 10);
+    /// timed_ring.push(1, 20);
+    /// timed_ring.push(2, 30);
+    ///
+    /// assert_eq!(timed_ring.size(), 2);
+    /// ```
+    pub fn push(&mut self, tick: u64, value: T) {
+        if let Some(last_tick) = self.last_tick() {
+            if tick < last_tick {
+                panic!("Ticks must be non-decreasing");
+            }
+        }
+
+        self.entries.enqueue((tick, value));
+    }
+
+    /// Returns number of values in the ring whose tick is greater than or equal to `tick`
+    /// * Complexity: O(log(n))
+    ///
+    /// # Arguments
+    /// * `tick`: start of the time window
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::queue::TimedRing;
+    ///
+    /// let mut timed_ring: TimedRing<usize> = TimedRing::new(4);
+    ///
+    /// timed_ring.push(1, 10);
+    /// timed_ring.push(3, 20);
+    /// timed_ring.push(5, 30);
+    ///
+    /// assert_eq!(timed_ring.count_since(0), 3);
+    /// assert_eq!(timed_ring.count_since(3), 2);
+    /// assert_eq!(timed_ring.count_since(6), 0);
+    /// ```
+    pub fn count_since(&self, tick: u64) -> usize {
+        let size = self.size();
+
+        // ticks are non-decreasing, so values older than `tick` form a prefix of the ring
+        size - partition_point(size, |i| self.entries[i].0 < tick)
+    }
+
+    /// Returns tick of the most recently pushed value. returns None if ring is empty
+    pub fn last_tick(&self) -> Option<u64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.entries[self.size() - 1].0)
+        }
+    }
+
+    /// Returns number of values in the ring
+    pub fn size(&self) -> usize {
+        self.entries.size()
+    }
+
+    /// Returns true if ring is empty, false otherwise
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns maximum number of values in the ring
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all values from the ring
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_timed_ring_count_since_1() {
+        let mut timed_ring: TimedRing<usize> = TimedRing::new(5);
+        assert_eq!(timed_ring.count_since(0), 0);
+
+        let mut ticks = Vec::new();
+        for i in 0..20 {
+            timed_ring.push(i * 10, i as usize);
+            ticks.push(i * 10);
+
+            // ring only keeps the last 5 values
+            let window = &ticks[ticks.len().saturating_sub(5)..];
+            for tick in 0..=(i * 10 + 1) {
+                let expected = window.iter().filter(|t| **t >= tick).count();
+
+                assert_eq!(timed_ring.count_since(tick), expected);
+            }
+        }
+
+        assert_eq!(timed_ring.size(), 5);
+        assert_eq!(timed_ring.last_tick(), Some(190));
+    }
+
+    #[test]
+    fn queue_timed_ring_count_since_2() {
+        let mut timed_ring: TimedRing<&str> = TimedRing::new(4);
+
+        timed_ring.push(1, "a");
+        timed_ring.push(1, "b");
+        timed_ring.push(2, "c");
+        timed_ring.push(2, "d");
+
+        assert_eq!(timed_ring.count_since(1), 4);
+        assert_eq!(timed_ring.count_since(2), 2);
+
+        // "a" falls out of the ring
+        timed_ring.push(3, "e");
+        assert_eq!(timed_ring.count_since(1), 4);
+        assert_eq!(timed_ring.count_since(2), 3);
+        assert_eq!(timed_ring.count_since(3), 1);
+
+        timed_ring.clear();
+        assert!(timed_ring.is_empty());
+        assert_eq!(timed_ring.count_since(0), 0);
+        assert_eq!(timed_ring.last_tick(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ticks must be non-decreasing")]
+    fn queue_timed_ring_panic_push() {
+        let mut timed_ring: TimedRing<usize> = TimedRing::new(3);
+
+        timed_ring.push(5, 1);
+        timed_ring.push(4, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity must be greater than zero")]
+    fn queue_timed_ring_panic_new() {
+        let _: TimedRing<usize> = TimedRing::new(0);
+    }
+}
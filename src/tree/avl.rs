@@ -1,4 +1,8 @@
+use std::cmp::Ordering;
 use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+use std::ops::Bound::*;
+use std::ops::RangeBounds;
 
 struct Node<K: std::cmp::Ord, V> {
     key: Option<K>,
@@ -96,6 +100,9 @@ impl<K: std::cmp::Ord, V> Node<K, V> {
 /// ```
 pub struct AVL<K: std::cmp::Ord, V> {
     root: Option<Box<Node<K, V>>>,
+
+    // preallocated empty nodes that are used by insertions before allocating new ones
+    spare_nodes: Vec<Box<Node<K, V>>>,
 }
 
 impl<K: std::cmp::Ord, V> AVL<K, V> {
@@ -112,7 +119,115 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
     /// let string_to_string = AVL::<String, String>::init();
     /// ```
     pub fn init() -> AVL<K, V> {
-        AVL { root: None }
+        AVL {
+            root: None,
+            spare_nodes: Vec::new(),
+        }
+    }
+
+    /// Initializes an empty AVL tree with preallocated nodes for `capacity` entries.
+    /// The first `capacity` insertions take their nodes from the preallocated ones instead of the allocator
+    ///
+    /// # Arguments
+    /// * `capacity`: number of nodes to preallocate
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::with_capacity(10);
+    /// assert_eq!(avl_tree.capacity(), 10);
+    ///
+    /// for i in 0..10 {
+    ///     avl_tree.insert(i, i);
+    /// }
+    /// assert_eq!(avl_tree.capacity(), 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> AVL<K, V> {
+        let mut avl_tree = AVL::init();
+        avl_tree.reserve(capacity);
+
+        avl_tree
+    }
+
+    /// Preallocates nodes so that at least `additional` more entries can be inserted without allocating.
+    /// It is a hint for bulk insertions, nodes of deleted entries are not kept for reuse
+    ///
+    /// # Arguments
+    /// * `additional`: number of entries expected to be inserted
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    /// avl_tree.insert(1, 1);
+    ///
+    /// avl_tree.reserve(5);
+    /// assert_eq!(avl_tree.capacity(), 6);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let missing = additional.saturating_sub(self.spare_nodes.len());
+
+        self.spare_nodes.reserve(missing);
+        for _ in 0..missing {
+            self.spare_nodes.push(Box::new(Node {
+                key: None,
+                value: None,
+                height: 0,
+                size: 1,
+                left_child: None,
+                right_child: None,
+            }));
+        }
+    }
+
+    /// Returns the number of entries the tree can hold without allocating new nodes
+    pub fn capacity(&self) -> usize {
+        self.size() + self.spare_nodes.len()
+    }
+
+    // takes a preallocated node for the new entry if there is any, otherwise allocates one
+    fn _new_node(spare_nodes: &mut Vec<Box<Node<K, V>>>, key: K, value: V) -> Box<Node<K, V>> {
+        match spare_nodes.pop() {
+            Some(mut node) => {
+                node.key = Some(key);
+                node.value = Some(value);
+                node
+            }
+            None => Box::new(Node::init(key, value, 0, 1)),
+        }
+    }
+
+    /// Builds a perfectly balanced AVL tree out of `entries` in linear time.
+    /// It is considerably faster than inserting entries one by one when building large trees
+    ///
+    /// # Arguments
+    /// * `entries`: key-value pairs sorted in strictly ascending order of their keys
+    ///
+    /// # Panics
+    /// * panics if keys of `entries` are not sorted in strictly ascending order
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let avl_tree = AVL::from_sorted(vec![(1, "a"), (3, "b"), (5, "c")]);
+    ///
+    /// assert_eq!(avl_tree.size(), 3);
+    /// assert_eq!(avl_tree.height(), 1);
+    /// assert_eq!(*avl_tree.get(&3).unwrap(), "b");
+    /// ```
+    pub fn from_sorted(entries: Vec<(K, V)>) -> AVL<K, V> {
+        if entries.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            panic!("Keys must be sorted in strictly ascending order");
+        }
+
+        let size = entries.len();
+        AVL {
+            root: AVL::_build_balanced(&mut entries.into_iter(), size),
+            spare_nodes: Vec::new(),
+        }
     }
 
     /// Returns `true` if tree is empty and `false` otherwise
@@ -158,6 +273,96 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
         Node::height(&self.root)
     }
 
+    /// Returns the number of edges from the root to the node containing `key`, `None` if `key` is not in the tree
+    ///
+    /// # Arguments
+    /// * `key`: key of the node
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(2,20);
+    /// avl_tree.insert(3,30);
+    ///
+    /// // 2 is the root and 1 and 3 are its children
+    /// assert_eq!(avl_tree.depth(&2), Some(0));
+    /// assert_eq!(avl_tree.depth(&3), Some(1));
+    /// assert_eq!(avl_tree.depth(&4), None);
+    /// ```
+    pub fn depth(&self, key: &K) -> Option<usize> {
+        let mut current = &self.root;
+        let mut depth = 0;
+
+        while let Some(node_ref) = current {
+            if *key < *node_ref.key() {
+                current = &node_ref.left_child;
+            } else if *key > *node_ref.key() {
+                current = &node_ref.right_child;
+            } else {
+                return Some(depth);
+            }
+
+            depth += 1;
+        }
+
+        None
+    }
+
+    /// Returns number of leaves in the tree, which are the nodes without any children
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(2,20);
+    /// avl_tree.insert(3,30);
+    ///
+    /// // 2 is the root and 1 and 3 are its children
+    /// assert_eq!(avl_tree.leaf_count(), 2);
+    /// ```
+    pub fn leaf_count(&self) -> usize {
+        AVL::_leaf_count(&self.root)
+    }
+
+    fn _leaf_count(node: &Option<Box<Node<K, V>>>) -> usize {
+        if node.is_none() {
+            return 0;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+
+        if node_ref.left_child.is_none() && node_ref.right_child.is_none() {
+            1
+        } else {
+            AVL::_leaf_count(&node_ref.left_child) + AVL::_leaf_count(&node_ref.right_child)
+        }
+    }
+
+    /// Returns number of internal nodes in the tree, which are the nodes with at least one child
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(2,20);
+    /// avl_tree.insert(3,30);
+    ///
+    /// assert_eq!(avl_tree.internal_count(), 1);
+    /// ```
+    pub fn internal_count(&self) -> usize {
+        self.size() - self.leaf_count()
+    }
+
     /// Returns `true` if tree contains the specified `key`, false otherwise
     ///
     /// # Arguments
@@ -179,6 +384,26 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
         !self.get(key).is_none()
     }
 
+    /// Returns `true` if tree contains the specified `key`, false otherwise.
+    /// It is an alias of `contains` matching the naming of maps in the standard library
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched in the tree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// assert!(avl_tree.contains_key(&1));
+    /// assert!(!avl_tree.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.contains(key)
+    }
+
     /// Returns a reference to value associated with specified `key` in tree, `None` otherwise
     /// # Arguments
     /// * `key`: key to be searched in the tree
@@ -215,12 +440,12 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
         }
     }
 
-    /// Insert a node which contains the specified `key` and `value` into the tree.
-    /// if `key` already exists, this method will replace `value` as the new value of the node
+    /// Returns a reference to the value associated with each key in `keys`, `None` for the keys that are not in the tree.
+    /// Keys are visited in sorted order so each node is visited at most once for the whole batch
+    /// * Complexity: O(k log(k) + min(n, k log(n))) where k is the number of `keys`
     ///
     /// # Arguments
-    /// * `key`: key of the new node
-    /// * `value`: value associated with the `key`
+    /// * `keys`: keys to be searched in the tree
     ///
     /// # Examples
     /// ```
@@ -231,84 +456,54 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
     /// avl_tree.insert(1,10);
     /// avl_tree.insert(2,20);
     /// avl_tree.insert(3,30);
-    /// avl_tree.insert(4,40);
-    /// assert_eq!(*avl_tree.get(&1).unwrap(), 10);
     ///
-    /// avl_tree.insert(1,11);
-    /// assert_eq!(*avl_tree.get(&1).unwrap(), 11);
+    /// assert_eq!(avl_tree.get_many(&[3, 4, 1]), vec![Some(&30), None, Some(&10)]);
     /// ```
-    pub fn insert(&mut self, key: K, value: V) {
-        self.root = AVL::_insert(self.root.take(), key, value);
-    }
-
-    fn _insert(node: Option<Box<Node<K, V>>>, key: K, value: V) -> Option<Box<Node<K, V>>> {
-        if node.is_none() {
-            return Some(Box::new(Node::init(key, value, 0, 1)));
-        }
+    pub fn get_many<'a>(&'a self, keys: &[K]) -> Vec<Option<&'a V>> {
+        let mut values = vec![None; keys.len()];
 
-        let mut node_ref = node.unwrap();
-
-        if key < *node_ref.key() {
-            node_ref.left_child = AVL::_insert(node_ref.left_child, key, value);
-        } else if key > *node_ref.key() {
-            node_ref.right_child = AVL::_insert(node_ref.right_child, key, value);
-        } else {
-            node_ref.value = Some(value);
-            return Some(node_ref);
-        }
+        // indices of `keys` sorted by key. sorting an already sorted `keys` is linear
+        let mut order: Vec<usize> = (0..keys.len()).collect();
+        order.sort_by(|a, b| keys[*a].cmp(&keys[*b]));
 
-        node_ref.update_height();
-        node_ref.update_size();
+        AVL::_get_many(&self.root, keys, &order, &mut values);
 
-        Some(AVL::balance(node_ref))
+        values
     }
 
-    fn balance(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
-        if Node::balance_factor(&node) < -1 {
-            if Node::balance_factor(node.right_child.as_ref().unwrap()) > 0 {
-                node.right_child = Some(AVL::rotate_right(node.right_child.unwrap()));
-            }
-            node = AVL::rotate_left(node);
-        } else if Node::balance_factor(&node) > 1 {
-            if Node::balance_factor(node.left_child.as_ref().unwrap()) < 0 {
-                node.left_child = Some(AVL::rotate_left(node.left_child.unwrap()));
-            }
-            node = AVL::rotate_right(node);
+    fn _get_many<'a>(
+        node: &'a Option<Box<Node<K, V>>>,
+        keys: &[K],
+        order: &[usize],
+        values: &mut Vec<Option<&'a V>>,
+    ) {
+        if node.is_none() || order.is_empty() {
+            return;
         }
-        node
-    }
-
-    fn rotate_right(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
-        let mut y = node.left_child.unwrap();
-        node.left_child = y.right_child;
-        y.size = node.size;
-        node.update_height();
-        node.update_size();
-
-        y.right_child = Some(node);
-        y.update_height();
-
-        y
-    }
 
-    fn rotate_left(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
-        let mut y = node.right_child.unwrap();
-        node.right_child = y.left_child;
-        y.size = node.size;
+        let node_ref = node.as_ref().unwrap();
 
-        node.update_height();
-        node.update_size();
+        // split the sorted keys into the ones less than, equal to and greater than key of the node
+        let less = order.partition_point(|i| keys[*i] < *node_ref.key());
+        let less_or_equal = order.partition_point(|i| keys[*i] <= *node_ref.key());
 
-        y.left_child = Some(node);
-        y.update_height();
+        for i in &order[less..less_or_equal] {
+            values[*i] = Some(node_ref.value());
+        }
 
-        y
+        AVL::_get_many(&node_ref.left_child, keys, &order[..less], values);
+        AVL::_get_many(&node_ref.right_child, keys, &order[less_or_equal..], values);
     }
 
-    /// Deletes the node containing the specified `key`
+    /// Returns mutable references to values associated with two different keys `a` and `b` at the same time.
+    /// Each reference is `None` if its key is not in the tree
     ///
     /// # Arguments
-    /// * `key`: key of the node to be deleted from the tree
+    /// * `a`: key of the first value
+    /// * `b`: key of the second value
+    ///
+    /// # Panics
+    /// * panics if `a` == `b`
     ///
     /// # Examples
     /// ```
@@ -318,59 +513,28 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
     ///
     /// avl_tree.insert(1,10);
     /// avl_tree.insert(2,20);
-    /// avl_tree.insert(3,30);
-    /// avl_tree.insert(4,40);
     ///
-    /// avl_tree.delete(&1);
-    /// assert_eq!(avl_tree.get(&1), None);
+    /// if let (Some(first), Some(second)) = avl_tree.get_disjoint_mut(&1, &2) {
+    ///     std::mem::swap(first, second);
+    /// }
+    ///
+    /// assert_eq!(*avl_tree.get(&1).unwrap(), 20);
+    /// assert_eq!(*avl_tree.get(&2).unwrap(), 10);
     /// ```
-    pub fn delete(&mut self, key: &K) {
-        if !self.is_empty() {
-            self.root = AVL::_delete(self.root.take(), key);
+    pub fn get_disjoint_mut(&mut self, a: &K, b: &K) -> (Option<&mut V>, Option<&mut V>) {
+        if *a == *b {
+            panic!("Keys must be different");
         }
-    }
-
-    fn _delete(node: Option<Box<Node<K, V>>>, key: &K) -> Option<Box<Node<K, V>>> {
-        match node {
-            None => node,
-            Some(mut _node) => {
-                if *key < *_node.key() {
-                    _node.left_child = AVL::_delete(_node.left_child.take(), key);
-                } else if *key > *_node.key() {
-                    _node.right_child = AVL::_delete(_node.right_child.take(), key);
-                } else {
-                    if _node.left_child.is_none() {
-                        return _node.right_child;
-                    } else if _node.right_child.is_none() {
-                        return _node.left_child;
-                    } else {
-                        let mut y = _node;
-                        _node = AVL::_min(&mut y.right_child);
-                        _node.right_child = AVL::_delete_min(y.right_child.unwrap());
-                        _node.left_child = y.left_child;
-                    }
-                }
 
-                _node.update_height();
-                _node.update_size();
-                Some(AVL::balance(_node))
-            }
-        }
-    }
-    fn _min(node: &mut Option<Box<Node<K, V>>>) -> Box<Node<K, V>> {
-        match node {
-            Some(_node) => {
-                if _node.left_child.is_none() {
-                    Box::new(Node::init(_node.get_key(), _node.get_value(), 0, 1))
-                } else {
-                    AVL::_min(&mut _node.left_child)
-                }
-            }
-            None => panic!("Called min on None node"),
-        }
+        AVL::_get_disjoint_mut(&mut self.root, a, b)
     }
 
-    /// Deletes node with smallest key from the tree
+    /// Swaps the values associated with keys `a` and `b` in place. Keys and structure of the tree are left untouched.
+    /// Returns false if either key is not in the tree, true otherwise
+    ///
+    /// # Arguments
+    /// * `a`: key of the first value
+    /// * `b`: key of the second value
     ///
     /// # Examples
     /// ```
@@ -380,36 +544,88 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
     ///
     /// avl_tree.insert(1,10);
     /// avl_tree.insert(2,20);
-    /// avl_tree.insert(3,30);
-    /// avl_tree.insert(4,40);
     ///
-    /// avl_tree.delete_min();
-    /// assert_eq!(avl_tree.get(&1), None);
+    /// assert!(avl_tree.swap_values(&1, &2));
+    /// assert_eq!(*avl_tree.get(&1).unwrap(), 20);
+    /// assert_eq!(*avl_tree.get(&2).unwrap(), 10);
     ///
-    ///
-    /// avl_tree.delete_min();
-    /// assert_eq!(avl_tree.get(&2), None);
+    /// assert!(!avl_tree.swap_values(&1, &3));
     /// ```
-    pub fn delete_min(&mut self) {
-        if !self.is_empty() {
-            self.root = AVL::_delete_min(self.root.take().unwrap());
+    pub fn swap_values(&mut self, a: &K, b: &K) -> bool {
+        if *a == *b {
+            return self.contains(a);
         }
-    }
 
-    fn _delete_min(mut node: Box<Node<K, V>>) -> Option<Box<Node<K, V>>> {
-        if node.left_child.is_none() {
-            return node.right_child.take();
+        match self.get_disjoint_mut(a, b) {
+            (Some(value_a), Some(value_b)) => {
+                std::mem::swap(value_a, value_b);
+                true
+            }
+            _ => false,
         }
+    }
 
-        node.left_child = AVL::_delete_min(node.left_child.unwrap());
-
-        node.update_height();
-        node.update_size();
+    fn _get_disjoint_mut<'a>(
+        node: &'a mut Option<Box<Node<K, V>>>,
+        a: &K,
+        b: &K,
+    ) -> (Option<&'a mut V>, Option<&'a mut V>) {
+        if node.is_none() {
+            return (None, None);
+        }
+
+        // borrow fields separately so both subtrees and the value can be handed out together
+        let Node {
+            key,
+            value,
+            left_child,
+            right_child,
+            ..
+        } = &mut **node.as_mut().unwrap();
+        let key = key.as_ref().unwrap();
+
+        match (a.cmp(key), b.cmp(key)) {
+            // both keys are in the same subtree
+            (Ordering::Less, Ordering::Less) => AVL::_get_disjoint_mut(left_child, a, b),
+            (Ordering::Greater, Ordering::Greater) => AVL::_get_disjoint_mut(right_child, a, b),
+
+            // paths of the keys diverge at this node
+            (Ordering::Equal, Ordering::Less) => (value.as_mut(), AVL::_get_mut(left_child, b)),
+            (Ordering::Equal, Ordering::Greater) => (value.as_mut(), AVL::_get_mut(right_child, b)),
+            (Ordering::Less, Ordering::Equal) => (AVL::_get_mut(left_child, a), value.as_mut()),
+            (Ordering::Greater, Ordering::Equal) => (AVL::_get_mut(right_child, a), value.as_mut()),
+            (Ordering::Less, Ordering::Greater) => {
+                (AVL::_get_mut(left_child, a), AVL::_get_mut(right_child, b))
+            }
+            (Ordering::Greater, Ordering::Less) => {
+                (AVL::_get_mut(right_child, a), AVL::_get_mut(left_child, b))
+            }
+            (Ordering::Equal, Ordering::Equal) => panic!("Keys must be different"),
+        }
+    }
 
-        Some(AVL::balance(node))
+    fn _get_mut<'a>(node: &'a mut Option<Box<Node<K, V>>>, key: &K) -> Option<&'a mut V> {
+        if node.is_none() {
+            return None;
+        }
+
+        let node_ref = node.as_mut().unwrap();
+
+        if *key < *node_ref.key() {
+            AVL::_get_mut(&mut node_ref.left_child, key)
+        } else if *key > *node_ref.key() {
+            AVL::_get_mut(&mut node_ref.right_child, key)
+        } else {
+            node_ref.value.as_mut()
+        }
     }
 
-    /// Deletes node with largest key from the tree
+    /// Insert a node which contains the specified `key` and `value` into the tree.
+    /// if `key` already exists, this method will replace `value` as the new value of the node
+    ///
+    /// # Arguments
+    /// * `key`: key of the new node
+    /// * `value`: value associated with the `key`
     ///
     /// # Examples
     /// ```
@@ -421,189 +637,269 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
     /// avl_tree.insert(2,20);
     /// avl_tree.insert(3,30);
     /// avl_tree.insert(4,40);
+    /// assert_eq!(*avl_tree.get(&1).unwrap(), 10);
     ///
-    /// avl_tree.delete_max();
-    /// assert_eq!(avl_tree.get(&4), None);
-    ///
-    ///
-    /// avl_tree.delete_max();
-    /// assert_eq!(avl_tree.get(&3), None);
+    /// avl_tree.insert(1,11);
+    /// assert_eq!(*avl_tree.get(&1).unwrap(), 11);
     /// ```
-    pub fn delete_max(&mut self) {
-        if !self.is_empty() {
-            self.root = AVL::_delete_max(self.root.take().unwrap());
-        }
+    pub fn insert(&mut self, key: K, value: V) {
+        self.root = AVL::_insert(self.root.take(), key, value, &mut self.spare_nodes);
     }
 
-    fn _delete_max(mut node: Box<Node<K, V>>) -> Option<Box<Node<K, V>>> {
-        if node.right_child.is_none() {
-            return node.left_child.take();
+    fn _insert(
+        node: Option<Box<Node<K, V>>>,
+        key: K,
+        value: V,
+        spare_nodes: &mut Vec<Box<Node<K, V>>>,
+    ) -> Option<Box<Node<K, V>>> {
+        if node.is_none() {
+            return Some(AVL::_new_node(spare_nodes, key, value));
         }
 
-        node.right_child = AVL::_delete_max(node.right_child.unwrap());
+        let mut node_ref = node.unwrap();
 
-        node.update_height();
-        node.update_size();
+        if key < *node_ref.key() {
+            node_ref.left_child = AVL::_insert(node_ref.left_child, key, value, spare_nodes);
+        } else if key > *node_ref.key() {
+            node_ref.right_child = AVL::_insert(node_ref.right_child, key, value, spare_nodes);
+        } else {
+            node_ref.value = Some(value);
+            return Some(node_ref);
+        }
 
-        Some(AVL::balance(node))
+        node_ref.update_height();
+        node_ref.update_size();
+
+        Some(AVL::balance(node_ref))
     }
 
-    /// Returns the largest key in the tree less than or equal to `key`
+    /// Returns a mutable reference to the value associated with `key`.
+    /// if `key` does not exist, it is inserted with the default value of `V` first.
+    /// Like `get_or_insert`, it walks the tree twice: once to find or insert `key` and once to borrow its value by rank
+    /// * Complexity: O(log(n)), two descents
     ///
     /// # Arguments
-    /// * `key`: key to be searched for
+    /// * `key`: key of the node to be returned or inserted
     ///
-    /// # Examples:
+    /// # Examples
     /// ```
     /// use rudac::tree::AVL;
     ///
-    /// let mut avl_tree = AVL::<usize,usize>::init();
+    /// let mut positions = AVL::<char, Vec<usize>>::init();
     ///
-    /// avl_tree.insert(1,10);
-    /// avl_tree.insert(3,20);
-    /// avl_tree.insert(5,30);
-    /// avl_tree.insert(7,40);
+    /// for (i, letter) in "abab".chars().enumerate() {
+    ///     positions.entry_or_default(letter).push(i);
+    /// }
     ///
-    /// assert_eq!(*avl_tree.floor(&2).unwrap(), 1);
-    /// assert_eq!(avl_tree.floor(&0), None);
+    /// assert_eq!(*positions.get(&'a').unwrap(), vec![0, 2]);
+    /// assert_eq!(*positions.get(&'b').unwrap(), vec![1, 3]);
     /// ```
-    pub fn floor(&self, key: &K) -> Option<&K> {
-        AVL::_floor(&self.root, key)
+    pub fn entry_or_default(&mut self, key: K) -> &mut V
+    where
+        V: Default,
+    {
+        self._get_or_insert_with(key, V::default).1
     }
 
-    fn _floor<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<&'a K> {
+    fn _select_mut(node: &mut Option<Box<Node<K, V>>>, k: usize) -> Option<&mut V> {
         if node.is_none() {
             return None;
         }
-        let node_ref = node.as_ref().unwrap();
-        if *key == *node_ref.key() {
-            return Some(node_ref.key());
-        } else if *key < *node_ref.key() {
-            return AVL::_floor(&node_ref.left_child, key);
-        }
-        let found_key = AVL::_floor(&node_ref.right_child, key);
-        if !found_key.is_none() {
-            return found_key;
+        let node_ref = node.as_mut().unwrap();
+
+        let t = Node::size(&node_ref.left_child);
+        if t > k {
+            AVL::_select_mut(&mut node_ref.left_child, k)
+        } else if t < k {
+            AVL::_select_mut(&mut node_ref.right_child, k - t - 1)
         } else {
-            return Some(node_ref.key());
+            node_ref.value.as_mut()
         }
     }
 
-    /// Returns the smallest key in the tree greater than or equal to `key`
+    /// Returns whether `key` already existed in the tree together with a mutable reference to its value.
+    /// if `key` does not exist, it is inserted with `value` first. otherwise `value` is dropped and the existing value is kept.
+    /// The tree is walked twice: one descent finds or inserts `key` and rebalances on the way up,
+    /// then a second descent borrows the value by the rank of `key`, since rebalancing moves nodes after they are found
+    /// * Complexity: O(log(n)), two descents
     ///
     /// # Arguments
-    /// * `key`: key to be searched for
+    /// * `key`: key of the node to be returned or inserted
+    /// * `value`: value to be inserted if `key` does not exist
     ///
-    /// # Examples:
+    /// # Examples
     /// ```
     /// use rudac::tree::AVL;
     ///
     /// let mut avl_tree = AVL::<usize,usize>::init();
     ///
-    /// avl_tree.insert(1,10);
-    /// avl_tree.insert(3,20);
-    /// avl_tree.insert(5,30);
-    /// avl_tree.insert(7,40);
+    /// let (existed, value) = avl_tree.get_or_insert(1, 10);
+    /// assert!(!existed);
+    /// *value += 1;
     ///
-    /// assert_eq!(*avl_tree.ceiling(&6).unwrap(), 7);
-    /// assert_eq!(avl_tree.ceiling(&8), None);
+    /// let (existed, value) = avl_tree.get_or_insert(1, 20);
+    /// assert!(existed);
+    /// assert_eq!(*value, 11);
     /// ```
-    pub fn ceiling(&self, key: &K) -> Option<&K> {
-        AVL::_ceiling(&self.root, key)
+    pub fn get_or_insert(&mut self, key: K, value: V) -> (bool, &mut V) {
+        self._get_or_insert_with(key, || value)
     }
 
-    fn _ceiling<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<&'a K> {
+    // finds or inserts `key` in one descent, then borrows its value in a second descent by the rank found on the way
+    fn _get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, make: F) -> (bool, &mut V) {
+        let (root, existed, rank) =
+            AVL::_get_or_insert(self.root.take(), key, make, &mut self.spare_nodes);
+        self.root = root;
+
+        (existed, AVL::_select_mut(&mut self.root, rank).unwrap())
+    }
+
+    // returns the rebalanced subtree, whether `key` already existed and the rank of `key` in the subtree
+    fn _get_or_insert<F: FnOnce() -> V>(
+        node: Option<Box<Node<K, V>>>,
+        key: K,
+        make: F,
+        spare_nodes: &mut Vec<Box<Node<K, V>>>,
+    ) -> (Option<Box<Node<K, V>>>, bool, usize) {
         if node.is_none() {
-            return None;
-        }
-        let node_ref = node.as_ref().unwrap();
-        if *key == *node_ref.key() {
-            return Some(node_ref.key());
-        } else if *key > *node_ref.key() {
-            return AVL::_ceiling(&node_ref.right_child, key);
+            return (Some(AVL::_new_node(spare_nodes, key, make())), false, 0);
         }
-        let found_key = AVL::_ceiling(&node_ref.left_child, key);
-        if !found_key.is_none() {
-            return found_key;
+
+        let mut node_ref = node.unwrap();
+        let left_size = Node::size(&node_ref.left_child);
+
+        let (existed, rank) = if key < *node_ref.key() {
+            let (left_child, existed, rank) =
+                AVL::_get_or_insert(node_ref.left_child.take(), key, make, spare_nodes);
+            node_ref.left_child = left_child;
+            (existed, rank)
+        } else if key > *node_ref.key() {
+            let (right_child, existed, rank) =
+                AVL::_get_or_insert(node_ref.right_child.take(), key, make, spare_nodes);
+            node_ref.right_child = right_child;
+            (existed, left_size + 1 + rank)
         } else {
-            return Some(node_ref.key());
+            return (Some(node_ref), true, left_size);
+        };
+
+        if existed {
+            return (Some(node_ref), true, rank);
         }
+
+        node_ref.update_height();
+        node_ref.update_size();
+
+        (Some(AVL::balance(node_ref)), false, rank)
     }
 
-    /// Returns the kth smallest key and its associated value in the tree
+    /// Applies `modify` to the value associated with `key` if it exists in the tree,
+    /// otherwise inserts `key` with `default` as its value. It only traverses the tree once
     ///
     /// # Arguments
-    /// * `k`: the order statistic
-    ///
-    /// # Panics
-    /// * panics if k is not in range: 0 <= k <= size - 1
+    /// * `key`: key of the node to be modified or inserted
+    /// * `default`: value to be inserted if `key` does not exist
+    /// * `modify`: closure applied to the existing value of `key`
     ///
     /// # Examples
     /// ```
     /// use rudac::tree::AVL;
     ///
-    /// let mut avl_tree = AVL::<usize,usize>::init();
+    /// let mut word_count = AVL::<&str,usize>::init();
     ///
-    /// avl_tree.insert(1,10);
-    /// avl_tree.insert(3,20);
-    /// avl_tree.insert(5,30);
-    /// avl_tree.insert(7,40);
+    /// for word in "a b a c a b".split(' ') {
+    ///     word_count.upsert(word, 1, |count| *count += 1);
+    /// }
     ///
-    /// let (key, value) = avl_tree.select(1).unwrap();
-    /// assert_eq!(*key, 3);
-    /// assert_eq!(*value, 20);
+    /// assert_eq!(*word_count.get(&"a").unwrap(), 3);
+    /// assert_eq!(*word_count.get(&"b").unwrap(), 2);
+    /// assert_eq!(*word_count.get(&"c").unwrap(), 1);
     /// ```
-    pub fn select(&self, k: usize) -> Option<(&K, &V)> {
-        if k > self.size() {
-            panic!("K must be in range 0 <= k <= size - 1");
-        }
-        AVL::_select(&self.root, k)
+    pub fn upsert<F: FnOnce(&mut V)>(&mut self, key: K, default: V, modify: F) {
+        self.root = AVL::_upsert(
+            self.root.take(),
+            key,
+            default,
+            modify,
+            &mut self.spare_nodes,
+        );
     }
 
-    fn _select(node: &Option<Box<Node<K, V>>>, k: usize) -> Option<(&K, &V)> {
+    fn _upsert<F: FnOnce(&mut V)>(
+        node: Option<Box<Node<K, V>>>,
+        key: K,
+        default: V,
+        modify: F,
+        spare_nodes: &mut Vec<Box<Node<K, V>>>,
+    ) -> Option<Box<Node<K, V>>> {
         if node.is_none() {
-            return None;
+            return Some(AVL::_new_node(spare_nodes, key, default));
         }
-        let node_ref = node.as_ref().unwrap();
 
-        let t = Node::size(&node_ref.left_child);
-        if t > k {
-            return AVL::_select(&node_ref.left_child, k);
-        } else if t < k {
-            return AVL::_select(&node_ref.right_child, k - t - 1);
+        let mut node_ref = node.unwrap();
+
+        if key < *node_ref.key() {
+            node_ref.left_child =
+                AVL::_upsert(node_ref.left_child, key, default, modify, spare_nodes);
+        } else if key > *node_ref.key() {
+            node_ref.right_child =
+                AVL::_upsert(node_ref.right_child, key, default, modify, spare_nodes);
         } else {
-            return Some((node_ref.key(), node_ref.value()));
+            modify(node_ref.value.as_mut().unwrap());
+            return Some(node_ref);
         }
+
+        node_ref.update_height();
+        node_ref.update_size();
+
+        Some(AVL::balance(node_ref))
     }
 
-    /// Returns the smallest key and its associated value in the tree
-    ///
-    /// # Examples
-    /// ```
-    /// use rudac::tree::AVL;
-    ///
-    /// let mut avl_tree = AVL::<usize,usize>::init();
-    ///
-    /// avl_tree.insert(1,10);
-    /// avl_tree.insert(3,20);
-    /// avl_tree.insert(5,30);
-    /// avl_tree.insert(7,40);
-    ///
-    /// let (key, value) = avl_tree.min().unwrap();
-    /// assert_eq!(*key, 1);
-    /// assert_eq!(*value, 10);
-    ///
-    /// avl_tree.delete_min();
-    ///
-    /// let (key, value) = avl_tree.min().unwrap();
-    /// assert_eq!(*key, 3);
-    /// assert_eq!(*value, 20);
-    /// ```
-    pub fn min(&self) -> Option<(&K, &V)> {
-        self.select(0)
+    fn balance(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+        if Node::balance_factor(&node) < -1 {
+            if Node::balance_factor(node.right_child.as_ref().unwrap()) > 0 {
+                node.right_child = Some(AVL::rotate_right(node.right_child.unwrap()));
+            }
+            node = AVL::rotate_left(node);
+        } else if Node::balance_factor(&node) > 1 {
+            if Node::balance_factor(node.left_child.as_ref().unwrap()) < 0 {
+                node.left_child = Some(AVL::rotate_left(node.left_child.unwrap()));
+            }
+            node = AVL::rotate_right(node);
+        }
+        node
     }
 
-    /// Returns the largest key and its associated value in the tree
+    fn rotate_right(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+        let mut y = node.left_child.unwrap();
+        node.left_child = y.right_child;
+        y.size = node.size;
+        node.update_height();
+        node.update_size();
+
+        y.right_child = Some(node);
+        y.update_height();
+
+        y
+    }
+
+    fn rotate_left(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+        let mut y = node.right_child.unwrap();
+        node.right_child = y.left_child;
+        y.size = node.size;
+
+        node.update_height();
+        node.update_size();
+
+        y.left_child = Some(node);
+        y.update_height();
+
+        y
+    }
+
+    /// Deletes the node containing the specified `key`
+    ///
+    /// # Arguments
+    /// * `key`: key of the node to be deleted from the tree
     ///
     /// # Examples
     /// ```
@@ -612,319 +908,1995 @@ impl<K: std::cmp::Ord, V> AVL<K, V> {
     /// let mut avl_tree = AVL::<usize,usize>::init();
     ///
     /// avl_tree.insert(1,10);
-    /// avl_tree.insert(3,20);
-    /// avl_tree.insert(5,30);
-    /// avl_tree.insert(7,40);
-    ///
-    /// let (key, value) = avl_tree.max().unwrap();
-    /// assert_eq!(*key, 7);
-    /// assert_eq!(*value, 40);
-    ///
-    /// avl_tree.delete_max();
+    /// avl_tree.insert(2,20);
+    /// avl_tree.insert(3,30);
+    /// avl_tree.insert(4,40);
     ///
-    /// let (key, value) = avl_tree.max().unwrap();
-    /// assert_eq!(*key, 5);
-    /// assert_eq!(*value, 30);
+    /// avl_tree.delete(&1);
+    /// assert_eq!(avl_tree.get(&1), None);
     /// ```
-    pub fn max(&self) -> Option<(&K, &V)> {
-        self.select(self.size() - 1)
+    pub fn delete(&mut self, key: &K) {
+        if !self.is_empty() {
+            self.root = AVL::_delete(self.root.take(), key);
+        }
     }
 
-    /// Returns the number of keys in the symbol table strictly less than `key`
-    ///
+    fn _delete(node: Option<Box<Node<K, V>>>, key: &K) -> Option<Box<Node<K, V>>> {
+        match node {
+            None => node,
+            Some(mut _node) => {
+                if *key < *_node.key() {
+                    _node.left_child = AVL::_delete(_node.left_child.take(), key);
+                } else if *key > *_node.key() {
+                    _node.right_child = AVL::_delete(_node.right_child.take(), key);
+                } else {
+                    if _node.left_child.is_none() {
+                        return _node.right_child;
+                    } else if _node.right_child.is_none() {
+                        return _node.left_child;
+                    } else {
+                        let mut y = _node;
+                        _node = AVL::_min(&mut y.right_child);
+                        _node.right_child = AVL::_delete_min(y.right_child.unwrap());
+                        _node.left_child = y.left_child;
+                    }
+                }
+
+                _node.update_height();
+                _node.update_size();
+                Some(AVL::balance(_node))
+            }
+        }
+    }
+    fn _min(node: &mut Option<Box<Node<K, V>>>) -> Box<Node<K, V>> {
+        match node {
+            Some(_node) => {
+                if _node.left_child.is_none() {
+                    Box::new(Node::init(_node.get_key(), _node.get_value(), 0, 1))
+                } else {
+                    AVL::_min(&mut _node.left_child)
+                }
+            }
+            None => panic!("Called min on None node"),
+        }
+    }
+
+    /// Deletes node with smallest key from the tree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(2,20);
+    /// avl_tree.insert(3,30);
+    /// avl_tree.insert(4,40);
+    ///
+    /// avl_tree.delete_min();
+    /// assert_eq!(avl_tree.get(&1), None);
+    ///
+    ///
+    /// avl_tree.delete_min();
+    /// assert_eq!(avl_tree.get(&2), None);
+    /// ```
+    pub fn delete_min(&mut self) {
+        if !self.is_empty() {
+            self.root = AVL::_delete_min(self.root.take().unwrap());
+        }
+    }
+
+    fn _delete_min(mut node: Box<Node<K, V>>) -> Option<Box<Node<K, V>>> {
+        if node.left_child.is_none() {
+            return node.right_child.take();
+        }
+
+        node.left_child = AVL::_delete_min(node.left_child.unwrap());
+
+        node.update_height();
+        node.update_size();
+
+        Some(AVL::balance(node))
+    }
+
+    /// Deletes node with largest key from the tree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(2,20);
+    /// avl_tree.insert(3,30);
+    /// avl_tree.insert(4,40);
+    ///
+    /// avl_tree.delete_max();
+    /// assert_eq!(avl_tree.get(&4), None);
+    ///
+    ///
+    /// avl_tree.delete_max();
+    /// assert_eq!(avl_tree.get(&3), None);
+    /// ```
+    pub fn delete_max(&mut self) {
+        if !self.is_empty() {
+            self.root = AVL::_delete_max(self.root.take().unwrap());
+        }
+    }
+
+    fn _delete_max(mut node: Box<Node<K, V>>) -> Option<Box<Node<K, V>>> {
+        if node.right_child.is_none() {
+            return node.left_child.take();
+        }
+
+        node.right_child = AVL::_delete_max(node.right_child.unwrap());
+
+        node.update_height();
+        node.update_size();
+
+        Some(AVL::balance(node))
+    }
+
+    /// Returns the largest key in the tree less than or equal to `key`
+    ///
     /// # Arguments
     /// * `key`: key to be searched for
     ///
-    /// # Examples
+    /// # Examples:
     /// ```
     /// use rudac::tree::AVL;
     ///
-    /// let mut avl_tree = AVL::<usize, usize>::init();
+    /// let mut avl_tree = AVL::<usize,usize>::init();
     ///
-    /// for i in 1..100 {
-    ///     avl_tree.insert(i, i);
-    /// }
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    /// avl_tree.insert(7,40);
     ///
-    /// assert_eq!(avl_tree.rank(&99), 98);
+    /// assert_eq!(*avl_tree.floor(&2).unwrap(), 1);
+    /// assert_eq!(avl_tree.floor(&0), None);
     /// ```
-    pub fn rank(&self, key: &K) -> usize {
-        AVL::_rank(&self.root, key)
+    pub fn floor(&self, key: &K) -> Option<&K> {
+        AVL::_floor(&self.root, key)
     }
-    fn _rank(node: &Option<Box<Node<K, V>>>, key: &K) -> usize {
+
+    fn _floor<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<&'a K> {
         if node.is_none() {
-            return 0;
+            return None;
         }
         let node_ref = node.as_ref().unwrap();
-        if *key < *node_ref.key() {
-            AVL::_rank(&node_ref.left_child, key)
+        if *key == *node_ref.key() {
+            return Some(node_ref.key());
+        } else if *key < *node_ref.key() {
+            return AVL::_floor(&node_ref.left_child, key);
+        }
+        let found_key = AVL::_floor(&node_ref.right_child, key);
+        if !found_key.is_none() {
+            return found_key;
+        } else {
+            return Some(node_ref.key());
+        }
+    }
+
+    /// Returns the smallest key in the tree greater than or equal to `key`
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched for
+    ///
+    /// # Examples:
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    /// avl_tree.insert(7,40);
+    ///
+    /// assert_eq!(*avl_tree.ceiling(&6).unwrap(), 7);
+    /// assert_eq!(avl_tree.ceiling(&8), None);
+    /// ```
+    pub fn ceiling(&self, key: &K) -> Option<&K> {
+        AVL::_ceiling(&self.root, key)
+    }
+
+    fn _ceiling<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<&'a K> {
+        if node.is_none() {
+            return None;
+        }
+        let node_ref = node.as_ref().unwrap();
+        if *key == *node_ref.key() {
+            return Some(node_ref.key());
         } else if *key > *node_ref.key() {
-            1 + Node::size(&node_ref.left_child) + AVL::_rank(&node_ref.right_child, key)
+            return AVL::_ceiling(&node_ref.right_child, key);
+        }
+        let found_key = AVL::_ceiling(&node_ref.left_child, key);
+        if !found_key.is_none() {
+            return found_key;
         } else {
-            Node::size(&node_ref.left_child)
+            return Some(node_ref.key());
         }
     }
 
-    /// Returns all keys in the tree following an in-order traversal.
-    /// Therefore keys are sorted from smallest to largest
+    /// Returns the smallest key in the tree strictly greater than `key` and its associated value
     ///
-    /// # Examples
+    /// # Arguments
+    /// * `key`: key to be searched for. it does not need to be present in the tree
+    ///
+    /// # Examples:
     /// ```
     /// use rudac::tree::AVL;
     ///
-    /// let mut avl_tree = AVL::<usize, usize>::init();
+    /// let mut avl_tree = AVL::<usize,usize>::init();
     ///
-    /// for i in (1..100).rev() {
-    ///     avl_tree.insert(i, i);
-    /// }
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    /// avl_tree.insert(7,40);
     ///
-    /// let mut i = 1;
-    /// // keys are sorted: [1, 2, 3,..., 99]
-    /// for key in avl_tree.keys() {
-    ///     assert!(*key == i);
-    ///     i += 1;
-    /// }
+    /// assert_eq!(avl_tree.successor_entry(&3), Some((&5, &30)));
+    /// assert_eq!(avl_tree.successor_entry(&4), Some((&5, &30)));
+    /// assert_eq!(avl_tree.successor_entry(&7), None);
     /// ```
-    pub fn keys(&self) -> Vec<&K> {
-        let mut keys: Vec<&K> = Vec::new();
+    pub fn successor_entry(&self, key: &K) -> Option<(&K, &V)> {
+        AVL::_successor_entry(&self.root, key)
+    }
 
-        AVL::_keys_in_order(&self.root, &mut keys);
+    fn _successor_entry<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<(&'a K, &'a V)> {
+        if node.is_none() {
+            return None;
+        }
+        let node_ref = node.as_ref().unwrap();
+        if *key >= *node_ref.key() {
+            return AVL::_successor_entry(&node_ref.right_child, key);
+        }
+        let found_entry = AVL::_successor_entry(&node_ref.left_child, key);
+        if found_entry.is_none() {
+            Some((node_ref.key(), node_ref.value()))
+        } else {
+            found_entry
+        }
+    }
 
-        keys
+    /// Returns the largest key in the tree strictly less than `key` and its associated value
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched for. it does not need to be present in the tree
+    ///
+    /// # Examples:
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    /// avl_tree.insert(7,40);
+    ///
+    /// assert_eq!(avl_tree.predecessor_entry(&5), Some((&3, &20)));
+    /// assert_eq!(avl_tree.predecessor_entry(&4), Some((&3, &20)));
+    /// assert_eq!(avl_tree.predecessor_entry(&1), None);
+    /// ```
+    pub fn predecessor_entry(&self, key: &K) -> Option<(&K, &V)> {
+        AVL::_predecessor_entry(&self.root, key)
+    }
+
+    fn _predecessor_entry<'a>(
+        node: &'a Option<Box<Node<K, V>>>,
+        key: &K,
+    ) -> Option<(&'a K, &'a V)> {
+        if node.is_none() {
+            return None;
+        }
+        let node_ref = node.as_ref().unwrap();
+        if *key <= *node_ref.key() {
+            return AVL::_predecessor_entry(&node_ref.left_child, key);
+        }
+        let found_entry = AVL::_predecessor_entry(&node_ref.right_child, key);
+        if found_entry.is_none() {
+            Some((node_ref.key(), node_ref.value()))
+        } else {
+            found_entry
+        }
+    }
+
+    /// Returns the first entry in the tree whose key is not less than `key`, `None` if there is no such entry.
+    /// If `key` is present in the tree, its own entry is returned
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched for. it does not need to be present in the tree
+    ///
+    /// # Examples:
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    ///
+    /// assert_eq!(avl_tree.lower_bound(&3), Some((&3, &20)));
+    /// assert_eq!(avl_tree.lower_bound(&4), Some((&5, &30)));
+    /// assert_eq!(avl_tree.lower_bound(&6), None);
+    /// ```
+    pub fn lower_bound(&self, key: &K) -> Option<(&K, &V)> {
+        AVL::_lower_bound(&self.root, key)
+    }
+
+    fn _lower_bound<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<(&'a K, &'a V)> {
+        if node.is_none() {
+            return None;
+        }
+        let node_ref = node.as_ref().unwrap();
+        if *key > *node_ref.key() {
+            return AVL::_lower_bound(&node_ref.right_child, key);
+        }
+        let found_entry = AVL::_lower_bound(&node_ref.left_child, key);
+        if found_entry.is_none() {
+            Some((node_ref.key(), node_ref.value()))
+        } else {
+            found_entry
+        }
+    }
+
+    /// Returns the first entry in the tree whose key is greater than `key`, `None` if there is no such entry.
+    /// Unlike `lower_bound`, entry of `key` itself is skipped if it is present in the tree
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched for. it does not need to be present in the tree
+    ///
+    /// # Examples:
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    ///
+    /// assert_eq!(avl_tree.upper_bound(&3), Some((&5, &30)));
+    /// assert_eq!(avl_tree.upper_bound(&0), Some((&1, &10)));
+    /// assert_eq!(avl_tree.upper_bound(&5), None);
+    /// ```
+    pub fn upper_bound(&self, key: &K) -> Option<(&K, &V)> {
+        AVL::_successor_entry(&self.root, key)
+    }
+
+    /// Returns the kth smallest key and its associated value in the tree
+    ///
+    /// # Arguments
+    /// * `k`: the order statistic
+    ///
+    /// # Panics
+    /// * panics if k is not in range: 0 <= k <= size - 1
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    /// avl_tree.insert(7,40);
+    ///
+    /// let (key, value) = avl_tree.select(1).unwrap();
+    /// assert_eq!(*key, 3);
+    /// assert_eq!(*value, 20);
+    /// ```
+    pub fn select(&self, k: usize) -> Option<(&K, &V)> {
+        if k > self.size() {
+            panic!("K must be in range 0 <= k <= size - 1");
+        }
+        AVL::_select(&self.root, k)
+    }
+
+    fn _select(node: &Option<Box<Node<K, V>>>, k: usize) -> Option<(&K, &V)> {
+        if node.is_none() {
+            return None;
+        }
+        let node_ref = node.as_ref().unwrap();
+
+        let t = Node::size(&node_ref.left_child);
+        if t > k {
+            return AVL::_select(&node_ref.left_child, k);
+        } else if t < k {
+            return AVL::_select(&node_ref.right_child, k - t - 1);
+        } else {
+            return Some((node_ref.key(), node_ref.value()));
+        }
+    }
+
+    /// Returns the smallest key and its associated value in the tree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    /// avl_tree.insert(7,40);
+    ///
+    /// let (key, value) = avl_tree.min().unwrap();
+    /// assert_eq!(*key, 1);
+    /// assert_eq!(*value, 10);
+    ///
+    /// avl_tree.delete_min();
+    ///
+    /// let (key, value) = avl_tree.min().unwrap();
+    /// assert_eq!(*key, 3);
+    /// assert_eq!(*value, 20);
+    /// ```
+    pub fn min(&self) -> Option<(&K, &V)> {
+        self.select(0)
+    }
+
+    /// Returns the largest key and its associated value in the tree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    /// avl_tree.insert(7,40);
+    ///
+    /// let (key, value) = avl_tree.max().unwrap();
+    /// assert_eq!(*key, 7);
+    /// assert_eq!(*value, 40);
+    ///
+    /// avl_tree.delete_max();
+    ///
+    /// let (key, value) = avl_tree.max().unwrap();
+    /// assert_eq!(*key, 5);
+    /// assert_eq!(*value, 30);
+    /// ```
+    pub fn max(&self) -> Option<(&K, &V)> {
+        self.select(self.size() - 1)
+    }
+
+    /// Returns the median key and its associated value in the tree. returns None if tree is empty.
+    /// Median is the key of rank `(size - 1) / 2`, so for an even number of keys the lower of the two middle keys is returned
+    /// * Complexity: O(log(n))
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    /// assert_eq!(avl_tree.median(), None);
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(3,20);
+    /// avl_tree.insert(5,30);
+    /// assert_eq!(avl_tree.median(), Some((&3, &20)));
+    ///
+    /// avl_tree.insert(7,40);
+    /// assert_eq!(avl_tree.median(), Some((&3, &20)));
+    /// ```
+    pub fn median(&self) -> Option<(&K, &V)> {
+        if self.is_empty() {
+            return None;
+        }
+        self.select((self.size() - 1) / 2)
+    }
+
+    /// Returns the number of keys in the symbol table strictly less than `key`
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched for
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in 1..100 {
+    ///     avl_tree.insert(i, i);
+    /// }
+    ///
+    /// assert_eq!(avl_tree.rank(&99), 98);
+    /// ```
+    pub fn rank(&self, key: &K) -> usize {
+        AVL::_rank(&self.root, key)
+    }
+    fn _rank(node: &Option<Box<Node<K, V>>>, key: &K) -> usize {
+        if node.is_none() {
+            return 0;
+        }
+        let node_ref = node.as_ref().unwrap();
+        if *key < *node_ref.key() {
+            AVL::_rank(&node_ref.left_child, key)
+        } else if *key > *node_ref.key() {
+            1 + Node::size(&node_ref.left_child) + AVL::_rank(&node_ref.right_child, key)
+        } else {
+            Node::size(&node_ref.left_child)
+        }
+    }
+
+    /// Returns the number of keys in the tree that are less than `key` together with the value of `key`.
+    /// value is `None` if `key` does not exist. Both are found in a single traversal of the tree
+    /// * Complexity: O(log(n))
+    ///
+    /// # Arguments
+    /// * `key`: key to be ranked
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in (1..100).step_by(2) {
+    ///     avl_tree.insert(i, i * 10);
+    /// }
+    ///
+    /// assert_eq!(avl_tree.rank_entry(&5), (2, Some(&50)));
+    /// assert_eq!(avl_tree.rank_entry(&6), (3, None));
+    /// ```
+    pub fn rank_entry(&self, key: &K) -> (usize, Option<&V>) {
+        let mut rank = 0;
+        let mut node = &self.root;
+
+        while let Some(node_ref) = node {
+            match key.cmp(node_ref.key()) {
+                Ordering::Less => node = &node_ref.left_child,
+                Ordering::Greater => {
+                    rank += 1 + Node::size(&node_ref.left_child);
+                    node = &node_ref.right_child;
+                }
+                Ordering::Equal => {
+                    return (
+                        rank + Node::size(&node_ref.left_child),
+                        Some(node_ref.value()),
+                    )
+                }
+            }
+        }
+
+        (rank, None)
+    }
+
+    /// Returns all keys in the tree following an in-order traversal.
+    /// Therefore keys are sorted from smallest to largest
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in (1..100).rev() {
+    ///     avl_tree.insert(i, i);
+    /// }
+    ///
+    /// let mut i = 1;
+    /// // keys are sorted: [1, 2, 3,..., 99]
+    /// for key in avl_tree.keys() {
+    ///     assert!(*key == i);
+    ///     i += 1;
+    /// }
+    /// ```
+    pub fn keys(&self) -> Vec<&K> {
+        let mut keys: Vec<&K> = Vec::new();
+
+        AVL::_keys_in_order(&self.root, &mut keys);
+
+        keys
+    }
+
+    fn _keys_in_order<'a>(node: &'a Option<Box<Node<K, V>>>, keys: &mut Vec<&'a K>) {
+        if node.is_none() {
+            return;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        AVL::_keys_in_order(&node_ref.left_child, keys);
+        keys.push(node_ref.key());
+        AVL::_keys_in_order(&node_ref.right_child, keys);
+    }
+
+    /// Returns a lazy iterator over the values in the tree following an in-order traversal.
+    /// Therefore values are sorted based on their associated keys from smallest to largest.
+    /// Unlike collecting all values into a vector, it only keeps the path to the next node: O(height) space
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in (1..100).rev() {
+    ///     avl_tree.insert(i, i * 10);
+    /// }
+    ///
+    /// // values are sorted by their keys: [10, 20, 30,..., 990]
+    /// assert!(avl_tree.values_iter().eq((1..100).map(|i| i * 10).collect::<Vec<_>>().iter()));
+    /// ```
+    pub fn values_iter(&self) -> impl Iterator<Item = &V> {
+        // nodes whose left subtree is visited but they themselves are not
+        let mut stack: Vec<&Node<K, V>> = Vec::new();
+        let mut current = self.root.as_deref();
+
+        std::iter::from_fn(move || {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left_child.as_deref();
+            }
+
+            let node = stack.pop()?;
+            current = node.right_child.as_deref();
+
+            Some(node.value())
+        })
+    }
+
+    // pushes references to entries of the subtree rooted at `node` into `entries` following an in-order traversal
+    fn _entries_in_order<'a>(node: &'a Option<Box<Node<K, V>>>, entries: &mut Vec<(&'a K, &'a V)>) {
+        if node.is_none() {
+            return;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        AVL::_entries_in_order(&node_ref.left_child, entries);
+        entries.push((node_ref.key(), node_ref.value()));
+        AVL::_entries_in_order(&node_ref.right_child, entries);
+    }
+
+    fn entries_in_order(&self) -> Vec<(&K, &V)> {
+        let mut entries = Vec::with_capacity(self.size());
+
+        AVL::_entries_in_order(&self.root, &mut entries);
+
+        entries
+    }
+
+    /// Returns the number of entries in the tree whose value is strictly less than `value`.
+    /// Since the tree is ordered by keys and not values, all nodes are visited. Complexity: O(n)
+    ///
+    /// # Arguments
+    /// * `value`: value to be compared against
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in 0..100 {
+    ///     avl_tree.insert(i, 100 - i);
+    /// }
+    ///
+    /// assert_eq!(avl_tree.count_values_less_than(&10), 9);
+    /// ```
+    pub fn count_values_less_than(&self, value: &V) -> usize
+    where
+        V: std::cmp::Ord,
+    {
+        AVL::_count_values_less_than(&self.root, value)
+    }
+
+    fn _count_values_less_than(node: &Option<Box<Node<K, V>>>, value: &V) -> usize
+    where
+        V: std::cmp::Ord,
+    {
+        if node.is_none() {
+            return 0;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        let mut count = AVL::_count_values_less_than(&node_ref.left_child, value)
+            + AVL::_count_values_less_than(&node_ref.right_child, value);
+
+        if *node_ref.value() < *value {
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns all keys in the tree following a level-order traversal
+    pub fn keys_in_level_order(&self) -> Vec<&K> {
+        let mut keys: Vec<&K> = Vec::new();
+
+        AVL::_keys_in_level_order(&self.root, &mut keys);
+
+        keys
+    }
+
+    fn _keys_in_level_order<'a>(node: &'a Option<Box<Node<K, V>>>, keys: &mut Vec<&'a K>) {
+        if node.is_none() {
+            return;
+        }
+
+        let mut queue = VecDeque::<&Option<Box<Node<K, V>>>>::with_capacity(Node::size(node));
+        queue.push_back(node);
+
+        while !queue.is_empty() {
+            let current_node = queue.pop_front().unwrap().as_ref().unwrap();
+            keys.push(current_node.key());
+
+            if !current_node.left_child.is_none() {
+                queue.push_back(&current_node.left_child);
+            }
+            if !current_node.right_child.is_none() {
+                queue.push_back(&current_node.right_child);
+            }
+        }
+    }
+
+    /// Returns keys of the tree grouped by level, from the root level down to the deepest level.
+    /// Keys of each level are ordered from left to right. An empty tree has no levels
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(2,20);
+    /// avl_tree.insert(3,30);
+    /// avl_tree.insert(4,40);
+    ///
+    /// assert_eq!(avl_tree.levels(), vec![vec![&2], vec![&1, &3], vec![&4]]);
+    /// ```
+    pub fn levels(&self) -> Vec<Vec<&K>> {
+        let mut levels: Vec<Vec<&K>> = Vec::new();
+
+        if self.root.is_none() {
+            return levels;
+        }
+
+        let mut current_level = vec![self.root.as_ref().unwrap()];
+
+        while !current_level.is_empty() {
+            let mut next_level = Vec::with_capacity(current_level.len() * 2);
+
+            for node in &current_level {
+                if let Some(left_child) = node.left_child.as_ref() {
+                    next_level.push(left_child);
+                }
+                if let Some(right_child) = node.right_child.as_ref() {
+                    next_level.push(right_child);
+                }
+            }
+
+            levels.push(current_level.iter().map(|node| node.key()).collect());
+            current_level = next_level;
+        }
+
+        levels
+    }
+
+    /// Returns `(depth, key)` pairs of all nodes in the tree following a pre-order traversal.
+    /// Depth of the root is 0. Useful for rendering the structure of the tree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize,usize>::init();
+    ///
+    /// avl_tree.insert(1,10);
+    /// avl_tree.insert(2,20);
+    /// avl_tree.insert(3,30);
+    ///
+    /// // 2 is the root and 1 and 3 are its children
+    /// assert_eq!(avl_tree.nodes(), vec![(0, &2), (1, &1), (1, &3)]);
+    /// ```
+    pub fn nodes(&self) -> Vec<(usize, &K)> {
+        let mut nodes: Vec<(usize, &K)> = Vec::with_capacity(self.size());
+
+        AVL::_nodes_in_pre_order(&self.root, 0, &mut nodes);
+
+        nodes
+    }
+
+    fn _nodes_in_pre_order<'a>(
+        node: &'a Option<Box<Node<K, V>>>,
+        depth: usize,
+        nodes: &mut Vec<(usize, &'a K)>,
+    ) {
+        if node.is_none() {
+            return;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        nodes.push((depth, node_ref.key()));
+        AVL::_nodes_in_pre_order(&node_ref.left_child, depth + 1, nodes);
+        AVL::_nodes_in_pre_order(&node_ref.right_child, depth + 1, nodes);
+    }
+
+    /// Returns all keys in the symbol table between `low_key`(inclusive) and `high_key`(exclusive)
+    ///
+    /// # Arguments
+    /// * `low_key`: lowest key of the range
+    /// * `high_key`: highest key of the range
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in (1..100).rev() {
+    ///     avl_tree.insert(i, i);
+    /// }
+    ///
+    /// let keys = avl_tree.keys_between(&1, &99);
+    ///
+    /// assert_eq!(keys.len(), 98);
+    /// ```
+    pub fn keys_between(&self, low_key: &K, high_key: &K) -> Vec<&K> {
+        let mut keys: Vec<&K> = Vec::new();
+
+        AVL::_keys_between(&self.root, low_key, high_key, &mut keys);
+
+        keys
+    }
+
+    fn _keys_between<'a>(
+        node: &'a Option<Box<Node<K, V>>>,
+        low_key: &K,
+        high_key: &K,
+        keys: &mut Vec<&'a K>,
+    ) {
+        if node.is_none() {
+            return;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        if *low_key < *node_ref.key() {
+            AVL::_keys_between(&node_ref.left_child, low_key, high_key, keys);
+        }
+        if *low_key <= *node_ref.key() && *node_ref.key() < *high_key {
+            keys.push(node_ref.key());
+        }
+        if *high_key > *node_ref.key() {
+            AVL::_keys_between(&node_ref.right_child, low_key, high_key, keys);
+        }
+    }
+
+    /// Returns keys in `range` paired with mutable references to their values, sorted by keys.
+    /// Subtrees that are out of `range` are not visited
+    ///
+    /// # Arguments
+    /// * `range`: range of keys
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in 0..10 {
+    ///     avl_tree.insert(i, i * 10);
+    /// }
+    ///
+    /// for (_, value) in avl_tree.range_mut(3..=5) {
+    ///     *value += 1;
+    /// }
+    ///
+    /// assert_eq!(*avl_tree.get(&2).unwrap(), 20);
+    /// assert_eq!(*avl_tree.get(&3).unwrap(), 31);
+    /// assert_eq!(*avl_tree.get(&5).unwrap(), 51);
+    /// assert_eq!(*avl_tree.get(&6).unwrap(), 60);
+    /// ```
+    pub fn range_mut<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(&K, &mut V)> {
+        let mut entries: Vec<(&K, &mut V)> = Vec::new();
+
+        AVL::_range_mut(&mut self.root, &range, &mut entries);
+
+        entries
+    }
+
+    fn _range_mut<'a, R: RangeBounds<K>>(
+        node: &'a mut Option<Box<Node<K, V>>>,
+        range: &R,
+        entries: &mut Vec<(&'a K, &'a mut V)>,
+    ) {
+        if node.is_none() {
+            return;
+        }
+
+        // borrow fields of the node separately so both children and the value can be borrowed mutably at once
+        let Node {
+            key,
+            value,
+            left_child,
+            right_child,
+            ..
+        } = node.as_mut().unwrap().as_mut();
+        let key = key.as_ref().unwrap();
+
+        let go_left = match range.start_bound() {
+            Included(start) | Excluded(start) => *start < *key,
+            Unbounded => true,
+        };
+        let go_right = match range.end_bound() {
+            Included(end) | Excluded(end) => *end > *key,
+            Unbounded => true,
+        };
+
+        if go_left {
+            AVL::_range_mut(left_child, range, entries);
+        }
+        if range.contains(key) {
+            entries.push((key, value.as_mut().unwrap()));
+        }
+        if go_right {
+            AVL::_range_mut(right_child, range, entries);
+        }
+    }
+
+    /// Returns the number of keys in the tree between `low_key`(inclusive) and `high_key`(exclusive)
+    ///
+    /// # Arguments
+    /// * `low_key`: lowest key of the range
+    /// * `high_key`: highest key of the range
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in (1..100).rev() {
+    ///     avl_tree.insert(i, i);
+    /// }
+    ///
+    /// let keys = avl_tree.size_between(&1, &99);
+    ///
+    /// assert_eq!(keys, 98);
+    /// ```
+    pub fn size_between(&self, low_key: &K, high_key: &K) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        if *low_key > *high_key {
+            return 0;
+        }
+
+        return self.rank(high_key) - self.rank(low_key);
+    }
+
+    /// Splits the tree into two at the given `key`.
+    /// Returns a new tree containing all entries with keys greater than or equal to `key`,
+    /// and leaves `self` with the entries whose keys are strictly less than `key`
+    ///
+    /// # Arguments
+    /// * `key`: key to split the tree at
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in 0..10 {
+    ///     avl_tree.insert(i, i);
+    /// }
+    ///
+    /// let higher_tree = avl_tree.split_off(&4);
+    ///
+    /// assert_eq!(avl_tree.size(), 4);
+    /// assert_eq!(*avl_tree.max().unwrap().0, 3);
+    ///
+    /// assert_eq!(higher_tree.size(), 6);
+    /// assert_eq!(*higher_tree.min().unwrap().0, 4);
+    /// ```
+    pub fn split_off(&mut self, key: &K) -> AVL<K, V> {
+        let mut entries: Vec<(K, V)> = Vec::with_capacity(self.size());
+
+        AVL::_into_entries(self.root.take(), &mut entries);
+
+        // entries are sorted, so everything from the split point onwards belongs to the new tree
+        let split_index = entries
+            .iter()
+            .position(|(entry_key, _)| *entry_key >= *key)
+            .unwrap_or(entries.len());
+        let higher_entries = entries.split_off(split_index);
+
+        let lower_size = entries.len();
+        self.root = AVL::_build_balanced(&mut entries.into_iter(), lower_size);
+
+        let higher_size = higher_entries.len();
+        AVL {
+            root: AVL::_build_balanced(&mut higher_entries.into_iter(), higher_size),
+            spare_nodes: Vec::new(),
+        }
+    }
+
+    /// Removes all entries for which `pred` returns true and returns them in ascending order of their keys.
+    /// The remaining entries are kept in the tree
+    ///
+    /// # Arguments
+    /// * `pred`: predicate deciding whether an entry should be extracted
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in 0..10 {
+    ///     avl_tree.insert(i, i * 10);
+    /// }
+    ///
+    /// let extracted = avl_tree.extract_if(|key, _| key % 2 == 0);
+    ///
+    /// assert_eq!(extracted, vec![(0, 0), (2, 20), (4, 40), (6, 60), (8, 80)]);
+    /// assert_eq!(avl_tree.size(), 5);
+    /// assert!(!avl_tree.contains(&2));
+    /// ```
+    pub fn extract_if<F>(&mut self, mut pred: F) -> Vec<(K, V)>
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let mut entries: Vec<(K, V)> = Vec::with_capacity(self.size());
+
+        AVL::_into_entries(self.root.take(), &mut entries);
+
+        let mut extracted = Vec::new();
+        let mut remaining = Vec::with_capacity(entries.len());
+        for (key, value) in entries {
+            if pred(&key, &value) {
+                extracted.push((key, value));
+            } else {
+                remaining.push((key, value));
+            }
+        }
+
+        let remaining_size = remaining.len();
+        self.root = AVL::_build_balanced(&mut remaining.into_iter(), remaining_size);
+
+        extracted
+    }
+
+    /// Deletes all entries whose keys are within `range` and returns the number of deleted entries.
+    /// Unlike `extract_if`, deleted entries are dropped instead of being collected
+    /// * Complexity: O(m * log(n)) where m is the number of deleted entries
+    ///
+    /// # Arguments
+    /// * `range`: range of keys to be deleted
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, usize>::init();
+    ///
+    /// for i in 0..10 {
+    ///     avl_tree.insert(i, i * 10);
+    /// }
+    ///
+    /// assert_eq!(avl_tree.remove_range(3..=5), 3);
+    /// assert_eq!(avl_tree.size(), 7);
+    /// assert!(!avl_tree.contains(&4));
+    /// assert!(avl_tree.contains(&6));
+    /// ```
+    pub fn remove_range<R: RangeBounds<K>>(&mut self, range: R) -> usize {
+        // keys within the range occupy consecutive ranks starting from `start`
+        let start = match range.start_bound() {
+            Included(start) => self.rank(start),
+            Excluded(start) => self.rank(start) + self.contains(start) as usize,
+            Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Included(end) => self.rank(end) + self.contains(end) as usize,
+            Excluded(end) => self.rank(end),
+            Unbounded => self.size(),
+        };
+
+        let count = end.saturating_sub(start);
+        for _ in 0..count {
+            self.root = AVL::_delete_select(self.root.take().unwrap(), start);
+        }
+
+        count
+    }
+
+    // deletes the node holding the kth smallest key of the subtree rooted at `node`
+    fn _delete_select(mut node: Box<Node<K, V>>, k: usize) -> Option<Box<Node<K, V>>> {
+        let t = Node::size(&node.left_child);
+        if t > k {
+            node.left_child = AVL::_delete_select(node.left_child.unwrap(), k);
+        } else if t < k {
+            node.right_child = AVL::_delete_select(node.right_child.unwrap(), k - t - 1);
+        } else if node.left_child.is_none() {
+            return node.right_child;
+        } else if node.right_child.is_none() {
+            return node.left_child;
+        } else {
+            let mut y = node;
+            node = AVL::_min(&mut y.right_child);
+            node.right_child = AVL::_delete_min(y.right_child.unwrap());
+            node.left_child = y.left_child;
+        }
+
+        node.update_height();
+        node.update_size();
+        Some(AVL::balance(node))
+    }
+
+    /// Moves all entries from `other` into `self`, leaving `other` empty.
+    /// If a key exists in both trees, the value from `other` replaces the value in `self`
+    /// * Complexity: O(n + m)
+    ///
+    /// # Arguments
+    /// * `other`: tree whose entries are moved into `self`
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::AVL;
+    ///
+    /// let mut avl_tree = AVL::<usize, &str>::init();
+    /// avl_tree.insert(1, "a");
+    /// avl_tree.insert(2, "b");
+    ///
+    /// let mut other = AVL::<usize, &str>::init();
+    /// other.insert(2, "c");
+    /// other.insert(3, "d");
+    ///
+    /// avl_tree.append(&mut other);
+    ///
+    /// assert!(other.is_empty());
+    /// assert_eq!(avl_tree.size(), 3);
+    /// assert_eq!(*avl_tree.get(&2).unwrap(), "c");
+    /// ```
+    pub fn append(&mut self, other: &mut AVL<K, V>) {
+        let mut entries: Vec<(K, V)> = Vec::with_capacity(self.size());
+        AVL::_into_entries(self.root.take(), &mut entries);
+
+        let mut other_entries: Vec<(K, V)> = Vec::with_capacity(other.size());
+        AVL::_into_entries(other.root.take(), &mut other_entries);
+
+        // merge the two sorted sequences of entries, entries of `other` win on equal keys
+        let mut merged = Vec::with_capacity(entries.len() + other_entries.len());
+        let mut entries = entries.into_iter().peekable();
+        let mut other_entries = other_entries.into_iter().peekable();
+
+        while let (Some((key, _)), Some((other_key, _))) = (entries.peek(), other_entries.peek()) {
+            match key.cmp(other_key) {
+                Ordering::Less => merged.push(entries.next().unwrap()),
+                Ordering::Greater => merged.push(other_entries.next().unwrap()),
+                Ordering::Equal => {
+                    entries.next();
+                    merged.push(other_entries.next().unwrap());
+                }
+            }
+        }
+        merged.extend(entries);
+        merged.extend(other_entries);
+
+        let size = merged.len();
+        self.root = AVL::_build_balanced(&mut merged.into_iter(), size);
+    }
+
+    // consumes the subtree rooted at `node` and pushes its entries into `entries` following an in-order traversal
+    fn _into_entries(node: Option<Box<Node<K, V>>>, entries: &mut Vec<(K, V)>) {
+        if node.is_none() {
+            return;
+        }
+
+        let mut node_ref = node.unwrap();
+        AVL::_into_entries(node_ref.left_child.take(), entries);
+        entries.push((node_ref.get_key(), node_ref.get_value()));
+        AVL::_into_entries(node_ref.right_child.take(), entries);
+    }
+
+    // builds a perfectly balanced subtree out of the next `size` entries of the sorted `entries` iterator
+    fn _build_balanced<I>(entries: &mut I, size: usize) -> Option<Box<Node<K, V>>>
+    where
+        I: Iterator<Item = (K, V)>,
+    {
+        if size == 0 {
+            return None;
+        }
+
+        let left_size = size / 2;
+        let left_child = AVL::_build_balanced(entries, left_size);
+
+        let (key, value) = entries.next().unwrap();
+        let mut node = Box::new(Node::init(key, value, 0, 1));
+
+        node.left_child = left_child;
+        node.right_child = AVL::_build_balanced(entries, size - left_size - 1);
+
+        node.update_height();
+        node.update_size();
+
+        Some(node)
+    }
+}
+
+impl<K: std::cmp::Ord + Clone, V: Clone> Clone for AVL<K, V> {
+    fn clone(&self) -> AVL<K, V> {
+        let mut avl_tree = AVL::init();
+        avl_tree.clone_from(self);
+
+        avl_tree
+    }
+
+    // overwrites the tree with a deep copy of `source`, reusing nodes of the tree wherever `source` has a node at the same position
+    fn clone_from(&mut self, source: &AVL<K, V>) {
+        AVL::_clone_from(&mut self.root, &source.root);
+    }
+}
+
+impl<K: std::cmp::Ord + Clone, V: Clone> AVL<K, V> {
+    fn _clone_from(node: &mut Option<Box<Node<K, V>>>, source: &Option<Box<Node<K, V>>>) {
+        if source.is_none() {
+            *node = None;
+            return;
+        }
+
+        let source_ref = source.as_ref().unwrap();
+
+        match node {
+            Some(node_ref) => {
+                node_ref.key.clone_from(&source_ref.key);
+                node_ref.value.clone_from(&source_ref.value);
+                node_ref.height = source_ref.height;
+                node_ref.size = source_ref.size;
+            }
+            None => {
+                *node = Some(Box::new(Node::init(
+                    source_ref.key().clone(),
+                    source_ref.value().clone(),
+                    source_ref.height,
+                    source_ref.size,
+                )));
+            }
+        }
+
+        let node_ref = node.as_mut().unwrap();
+        AVL::_clone_from(&mut node_ref.left_child, &source_ref.left_child);
+        AVL::_clone_from(&mut node_ref.right_child, &source_ref.right_child);
+    }
+}
+
+/// Returns a reference to the value associated with `key`
+///
+/// # Panics
+/// * panics if `key` does not exist in the tree
+///
+/// # Examples
+/// ```
+/// use rudac::tree::AVL;
+///
+/// let mut avl_tree = AVL::<usize, usize>::init();
+///
+/// avl_tree.insert(1, 10);
+/// assert_eq!(avl_tree[&1], 10);
+///
+/// avl_tree[&1] = 20;
+/// assert_eq!(avl_tree[&1], 20);
+/// ```
+impl<K: std::cmp::Ord, V> std::ops::Index<&K> for AVL<K, V> {
+    type Output = V;
+
+    fn index(&self, key: &K) -> &Self::Output {
+        match AVL::_get(&self.root, key) {
+            Some(value) => value,
+            None => panic!("Key does not exist in the tree"),
+        }
+    }
+}
+
+/// Returns a mutable reference to the value associated with `key`
+///
+/// # Panics
+/// * panics if `key` does not exist in the tree
+impl<K: std::cmp::Ord, V> std::ops::IndexMut<&K> for AVL<K, V> {
+    fn index_mut(&mut self, key: &K) -> &mut Self::Output {
+        match AVL::_get_mut(&mut self.root, key) {
+            Some(value) => value,
+            None => panic!("Key does not exist in the tree"),
+        }
+    }
+}
+
+/// Two trees are equal if they contain the same entries, regardless of their shape
+impl<K: std::cmp::Ord, V: PartialEq> PartialEq for AVL<K, V> {
+    fn eq(&self, other: &AVL<K, V>) -> bool {
+        self.size() == other.size() && self.entries_in_order() == other.entries_in_order()
+    }
+}
+
+impl<K: std::cmp::Ord, V: Eq> Eq for AVL<K, V> {}
+
+/// Hashes the entries in ascending order of their keys,
+/// so trees with the same entries hash equally regardless of their shape
+///
+/// # Examples
+/// ```
+/// use rudac::tree::AVL;
+/// use std::collections::HashSet;
+///
+/// let mut avl_tree1 = AVL::<usize, usize>::init();
+/// avl_tree1.insert(1, 10);
+/// avl_tree1.insert(2, 20);
+///
+/// let mut avl_tree2 = AVL::<usize, usize>::init();
+/// avl_tree2.insert(2, 20);
+/// avl_tree2.insert(1, 10);
+///
+/// let mut set = HashSet::new();
+/// set.insert(avl_tree1);
+///
+/// assert!(set.contains(&avl_tree2));
+/// ```
+impl<K: std::cmp::Ord + Hash, V: Hash> Hash for AVL<K, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entries_in_order().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_avl<K: std::cmp::Ord, V>(node: &Option<Box<Node<K, V>>>) -> bool {
+        if node.is_none() {
+            return true;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        let balance_factor = Node::balance_factor(node_ref);
+
+        if balance_factor < -1 || balance_factor > 1 {
+            return false;
+        }
+
+        return is_avl(&node_ref.left_child) && is_avl(&node_ref.right_child);
+    }
+
+    fn is_bst<K: std::cmp::Ord, V>(
+        node: &Option<Box<Node<K, V>>>,
+        min: Option<&K>,
+        max: Option<&K>,
+    ) -> bool {
+        if node.is_none() {
+            return true;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        if !min.is_none() && *node_ref.key() <= **min.as_ref().unwrap() {
+            return false;
+        }
+        if !max.is_none() && *node_ref.key() >= **max.as_ref().unwrap() {
+            return false;
+        }
+
+        return is_bst(&node_ref.left_child, min, Some(node_ref.key()))
+            && is_bst(&node_ref.right_child, Some(node_ref.key()), max);
+    }
+
+    fn is_size_consistent<K: std::cmp::Ord, V>(node: &Option<Box<Node<K, V>>>) -> bool {
+        if node.is_none() {
+            return true;
+        }
+        let node_ref = node.as_ref().unwrap();
+
+        if Node::size(node)
+            != Node::size(&node_ref.left_child) + Node::size(&node_ref.right_child) + 1
+        {
+            return false;
+        }
+
+        return is_size_consistent(&node_ref.left_child)
+            && is_size_consistent(&node_ref.right_child);
+    }
+
+    fn is_rank_consistent<K: std::cmp::Ord, V>(avl_tree: &AVL<K, V>) -> bool {
+        for i in 0..Node::size(&avl_tree.root) {
+            if i != avl_tree.rank(avl_tree.select(i).unwrap().0) {
+                return false;
+            }
+        }
+
+        for key in avl_tree.keys() {
+            if *avl_tree.select(avl_tree.rank(key)).unwrap().0 != *key {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    #[test]
+    fn tree_avl_node_max_height() {
+        assert_eq!(Node::<usize, usize>::_max_height(&None, &None), -1);
+        assert_eq!(
+            Node::<usize, usize>::_max_height(&Some(Box::new(Node::init(1, 1, 0, 0))), &None),
+            0
+        );
+        assert_eq!(
+            Node::<usize, usize>::_max_height(
+                &Some(Box::new(Node::init(1, 1, 1, 0))),
+                &Some(Box::new(Node::init(1, 1, 2, 0)))
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn tree_avl_node_update_height() {
+        let mut root = Node::init(1, 1, 10, 0);
+        let mut left = Node::init(1, 1, 20, 0);
+        let mut right = Node::init(1, 1, 30, 0);
+
+        left.update_height();
+        right.update_height();
+
+        root.left_child = Some(Box::new(left));
+        root.right_child = Some(Box::new(right));
+
+        root.update_height();
+
+        assert_eq!(root.height, 1);
+        assert_eq!(root.left_child.unwrap().height, 0);
+        assert_eq!(root.right_child.unwrap().height, 0);
+    }
+
+    #[test]
+    fn tree_avl_init() {
+        let avl_tree = AVL::<usize, usize>::init();
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_insert_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        avl_tree.insert(1, 1);
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_insert_2() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        avl_tree.insert(4, 1);
+        avl_tree.insert(3, 1);
+        avl_tree.insert(2, 1);
+        avl_tree.insert(1, 1);
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_insert_3() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in (0..100).rev() {
+            avl_tree.insert(i, i);
+        }
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_delete_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        avl_tree.delete(&1);
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_delete_2() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        avl_tree.insert(4, 1);
+        avl_tree.insert(3, 1);
+        avl_tree.insert(2, 1);
+        avl_tree.insert(1, 1);
+
+        avl_tree.delete(&1);
+        assert_eq!(avl_tree.get(&1), None);
+
+        avl_tree.delete(&3);
+        assert_eq!(avl_tree.get(&3), None);
+
+        avl_tree.delete(&2);
+        assert_eq!(avl_tree.get(&2), None);
+
+        avl_tree.delete(&4);
+        assert_eq!(avl_tree.get(&4), None);
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_is_empty() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        assert!(avl_tree.is_empty());
+
+        avl_tree.insert(1, 1);
+
+        assert!(!avl_tree.is_empty());
+    }
+
+    #[test]
+    fn tree_avl_size_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+        assert_eq!(avl_tree.size(), 0);
+
+        avl_tree.insert(1, 1);
+        assert_eq!(avl_tree.size(), 1);
+
+        avl_tree.insert(2, 1);
+        assert_eq!(avl_tree.size(), 2);
+
+        avl_tree.insert(3, 1);
+        assert_eq!(avl_tree.size(), 3);
+
+        avl_tree.insert(4, 1);
+        assert_eq!(avl_tree.size(), 4);
+    }
+
+    #[test]
+    fn tree_avl_contains_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+        assert!(!avl_tree.contains(&1));
+
+        avl_tree.insert(1, 2);
+        assert!(avl_tree.contains(&1));
+
+        avl_tree.insert(1, 3);
+        assert!(avl_tree.contains(&1));
+    }
+
+    #[test]
+    fn tree_avl_get_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+        assert_eq!(avl_tree.get(&1), None);
+
+        avl_tree.insert(1, 2);
+        assert_eq!(*avl_tree.get(&1).unwrap(), 2);
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_get_2() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in (0..100).rev() {
+            avl_tree.insert(i, i);
+        }
+
+        for i in (0..100).rev() {
+            assert_eq!(*avl_tree.get(&i).unwrap(), i);
+        }
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_get_3() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in (0..100).rev() {
+            avl_tree.insert(i, i);
+        }
+
+        for i in (0..100).rev() {
+            assert_eq!(*avl_tree.get(&i).unwrap(), i);
+        }
+
+        for i in (0..100).rev() {
+            avl_tree.insert(i, i + 1);
+        }
+
+        for i in (0..100).rev() {
+            assert_eq!(*avl_tree.get(&i).unwrap(), i + 1);
+        }
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_delete_min_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        avl_tree.delete_min();
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_delete_min_2() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in (0..100).rev() {
+            avl_tree.insert(i, i);
+        }
+
+        for i in 0..100 {
+            avl_tree.delete_min();
+            assert_eq!(avl_tree.get(&i), None);
+        }
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_delete_max_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        avl_tree.delete_max();
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_delete_max_2() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in (0..100).rev() {
+            avl_tree.insert(i, i);
+        }
+
+        for i in (0..100).rev() {
+            avl_tree.delete_max();
+            assert_eq!(avl_tree.get(&i), None);
+        }
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_floor_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in (0..100).step_by(2) {
+            avl_tree.insert(i, i);
+        }
+
+        for i in (1..100).step_by(2) {
+            assert_eq!(*avl_tree.floor(&i).unwrap(), i - 1);
+        }
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
+    #[test]
+    fn tree_avl_ceiling_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in (0..100).step_by(2) {
+            avl_tree.insert(i, i);
+        }
+
+        for i in (1..99).step_by(2) {
+            assert_eq!(*avl_tree.ceiling(&i).unwrap(), i + 1);
+        }
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
     }
 
-    fn _keys_in_order<'a>(node: &'a Option<Box<Node<K, V>>>, keys: &mut Vec<&'a K>) {
-        if node.is_none() {
-            return;
+    #[test]
+    fn tree_avl_from_sorted_1() {
+        let avl_tree = AVL::from_sorted((0..100_000).map(|i| (i, i * 2)).collect());
+
+        assert_eq!(avl_tree.size(), 100_000);
+        assert_eq!(avl_tree.height(), 16);
+        for i in (0..100_000).step_by(997) {
+            assert_eq!(*avl_tree.get(&i).unwrap(), i * 2);
         }
 
-        let node_ref = node.as_ref().unwrap();
-        AVL::_keys_in_order(&node_ref.left_child, keys);
-        keys.push(node_ref.key());
-        AVL::_keys_in_order(&node_ref.right_child, keys);
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
     }
 
-    /// Returns all keys in the tree following a level-order traversal
-    pub fn keys_in_level_order(&self) -> Vec<&K> {
-        let mut keys: Vec<&K> = Vec::new();
+    #[test]
+    fn tree_avl_from_sorted_2() {
+        let avl_tree = AVL::<usize, usize>::from_sorted(vec![]);
+        assert!(avl_tree.is_empty());
 
-        AVL::_keys_in_level_order(&self.root, &mut keys);
+        let mut avl_tree = AVL::from_sorted(vec![(0, 0), (2, 2)]);
+        avl_tree.insert(1, 1);
+        avl_tree.delete(&0);
 
-        keys
+        assert_eq!(avl_tree.keys(), vec![&1, &2]);
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
     }
 
-    fn _keys_in_level_order<'a>(node: &'a Option<Box<Node<K, V>>>, keys: &mut Vec<&'a K>) {
-        if node.is_none() {
-            return;
+    #[test]
+    #[should_panic(expected = "Keys must be sorted in strictly ascending order")]
+    fn tree_avl_panic_from_sorted() {
+        AVL::from_sorted(vec![(0, 0), (2, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn tree_avl_get_disjoint_mut_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in 0..100 {
+            avl_tree.insert(i, i);
         }
 
-        let mut queue = VecDeque::<&Option<Box<Node<K, V>>>>::with_capacity(Node::size(node));
-        queue.push_back(node);
+        for i in 0..99 {
+            let (first, second) = avl_tree.get_disjoint_mut(&(i + 1), &i);
+            let first = first.unwrap();
+            let second = second.unwrap();
 
-        while !queue.is_empty() {
-            let current_node = queue.pop_front().unwrap().as_ref().unwrap();
-            keys.push(current_node.key());
+            *first += *second;
+            *second = 0;
+        }
 
-            if !current_node.left_child.is_none() {
-                queue.push_back(&current_node.left_child);
-            }
-            if !current_node.right_child.is_none() {
-                queue.push_back(&current_node.right_child);
-            }
+        // every value was moved into the value of the next key
+        assert_eq!(*avl_tree.get(&99).unwrap(), (0..100).sum::<usize>());
+        for i in 0..99 {
+            assert_eq!(*avl_tree.get(&i).unwrap(), 0);
         }
-    }
 
-    /// Returns all keys in the symbol table between `low_key`(inclusive) and `high_key`(exclusive)
-    ///
-    /// # Arguments
-    /// * `low_key`: lowest key of the range
-    /// * `high_key`: highest key of the range
-    ///
-    /// # Examples
-    /// ```
-    /// use rudac::tree::AVL;
-    ///
-    /// let mut avl_tree = AVL::<usize, usize>::init();
-    ///
-    /// for i in (1..100).rev() {
-    ///     avl_tree.insert(i, i);
-    /// }
-    ///
-    /// let keys = avl_tree.keys_between(&1, &99);
-    ///
-    /// assert_eq!(keys.len(), 98);
-    /// ```
-    pub fn keys_between(&self, low_key: &K, high_key: &K) -> Vec<&K> {
-        let mut keys: Vec<&K> = Vec::new();
+        let (first, second) = avl_tree.get_disjoint_mut(&100, &50);
+        assert_eq!(first, None);
+        assert_eq!(second, Some(&mut 0));
 
-        AVL::_keys_between(&self.root, low_key, high_key, &mut keys);
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
 
-        keys
+    #[test]
+    #[should_panic(expected = "Keys must be different")]
+    fn tree_avl_panic_get_disjoint_mut() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+        avl_tree.insert(1, 1);
+
+        avl_tree.get_disjoint_mut(&1, &1);
     }
 
-    fn _keys_between<'a>(
-        node: &'a Option<Box<Node<K, V>>>,
-        low_key: &K,
-        high_key: &K,
-        keys: &mut Vec<&'a K>,
-    ) {
-        if node.is_none() {
-            return;
-        }
+    #[test]
+    fn tree_avl_upsert_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
 
-        let node_ref = node.as_ref().unwrap();
-        if *low_key < *node_ref.key() {
-            AVL::_keys_between(&node_ref.left_child, low_key, high_key, keys);
+        // every key is inserted on its first occurrence and modified on the following ones
+        for i in 0..300 {
+            avl_tree.upsert(i % 100, i, |value| *value += i);
         }
-        if *low_key <= *node_ref.key() && *node_ref.key() < *high_key {
-            keys.push(node_ref.key());
-        }
-        if *high_key > *node_ref.key() {
-            AVL::_keys_between(&node_ref.right_child, low_key, high_key, keys);
+
+        assert_eq!(avl_tree.size(), 100);
+        for i in 0..100 {
+            assert_eq!(*avl_tree.get(&i).unwrap(), i + (i + 100) + (i + 200));
         }
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
     }
 
-    /// Returns the number of keys in the tree between `low_key`(inclusive) and `high_key`(exclusive)
-    ///
-    /// # Arguments
-    /// * `low_key`: lowest key of the range
-    /// * `high_key`: highest key of the range
-    ///
-    /// # Examples
-    /// ```
-    /// use rudac::tree::AVL;
-    ///
-    /// let mut avl_tree = AVL::<usize, usize>::init();
-    ///
-    /// for i in (1..100).rev() {
-    ///     avl_tree.insert(i, i);
-    /// }
-    ///
-    /// let keys = avl_tree.size_between(&1, &99);
-    ///
-    /// assert_eq!(keys, 98);
-    /// ```
-    pub fn size_between(&self, low_key: &K, high_key: &K) -> usize {
-        if self.is_empty() {
-            return 0;
-        }
-        if *low_key > *high_key {
-            return 0;
-        }
+    #[test]
+    fn tree_avl_upsert_2() {
+        let mut avl_tree = AVL::<usize, String>::init();
+        let mut modify_calls = 0;
 
-        return self.rank(high_key) - self.rank(low_key);
+        avl_tree.upsert(1, String::from("a"), |_| modify_calls += 1);
+        assert_eq!(modify_calls, 0);
+        assert_eq!(*avl_tree.get(&1).unwrap(), String::from("a"));
+
+        avl_tree.upsert(1, String::from("b"), |value| {
+            modify_calls += 1;
+            value.push('c');
+        });
+        assert_eq!(modify_calls, 1);
+        assert_eq!(*avl_tree.get(&1).unwrap(), String::from("ac"));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn tree_avl_depth_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+        assert_eq!(avl_tree.depth(&0), None);
 
-    fn is_avl<K: std::cmp::Ord, V>(node: &Option<Box<Node<K, V>>>) -> bool {
-        if node.is_none() {
-            return true;
+        // inserting 0..7 in order yields a perfect tree rooted at 3
+        for i in 0..7 {
+            avl_tree.insert(i, i);
         }
 
-        let node_ref = node.as_ref().unwrap();
-        let balance_factor = Node::balance_factor(node_ref);
-
-        if balance_factor < -1 || balance_factor > 1 {
-            return false;
+        assert_eq!(avl_tree.depth(&3), Some(0));
+        assert_eq!(avl_tree.depth(&1), Some(1));
+        assert_eq!(avl_tree.depth(&5), Some(1));
+        for leaf in [0, 2, 4, 6].iter() {
+            assert_eq!(avl_tree.depth(leaf), Some(2));
         }
+        assert_eq!(avl_tree.depth(&7), None);
 
-        return is_avl(&node_ref.left_child) && is_avl(&node_ref.right_child);
+        // depth of every key is bounded by the height of the tree
+        for i in 7..1000 {
+            avl_tree.insert(i, i);
+        }
+        for i in 0..1000 {
+            assert!(avl_tree.depth(&i).unwrap() as i64 <= avl_tree.height());
+        }
     }
 
-    fn is_bst<K: std::cmp::Ord, V>(
-        node: &Option<Box<Node<K, V>>>,
-        min: Option<&K>,
-        max: Option<&K>,
-    ) -> bool {
-        if node.is_none() {
-            return true;
+    #[test]
+    fn tree_avl_clone_from_1() {
+        let mut source = AVL::<usize, String>::init();
+        for i in 0..100 {
+            source.insert(i, i.to_string());
         }
 
-        let node_ref = node.as_ref().unwrap();
-        if !min.is_none() && *node_ref.key() <= **min.as_ref().unwrap() {
-            return false;
-        }
-        if !max.is_none() && *node_ref.key() >= **max.as_ref().unwrap() {
-            return false;
+        // clone into an empty tree, a smaller tree and a larger tree
+        let mut small_tree = AVL::<usize, String>::init();
+        small_tree.insert(1000, String::from("1000"));
+        let mut large_tree = AVL::<usize, String>::init();
+        for i in 200..500 {
+            large_tree.insert(i, i.to_string());
         }
 
-        return is_bst(&node_ref.left_child, min, Some(node_ref.key()))
-            && is_bst(&node_ref.right_child, Some(node_ref.key()), max);
-    }
+        for mut avl_tree in [AVL::init(), small_tree, large_tree] {
+            avl_tree.clone_from(&source);
 
-    fn is_size_consistent<K: std::cmp::Ord, V>(node: &Option<Box<Node<K, V>>>) -> bool {
-        if node.is_none() {
-            return true;
+            assert_eq!(avl_tree.keys_in_level_order(), source.keys_in_level_order());
+            for i in 0..100 {
+                assert_eq!(avl_tree.get(&i), source.get(&i));
+            }
+            assert_eq!(avl_tree.get(&1000), None);
+            assert_eq!(avl_tree.get(&200), None);
+
+            assert!(is_avl(&avl_tree.root));
+            assert!(is_bst(&avl_tree.root, None, None));
+            assert!(is_size_consistent(&avl_tree.root));
+            assert!(is_rank_consistent(&avl_tree));
         }
-        let node_ref = node.as_ref().unwrap();
+    }
 
-        if Node::size(node)
-            != Node::size(&node_ref.left_child) + Node::size(&node_ref.right_child) + 1
-        {
-            return false;
+    #[test]
+    fn tree_avl_clone_1() {
+        let mut source = AVL::<usize, usize>::init();
+        for i in 0..100 {
+            source.insert(i, i);
         }
 
-        return is_size_consistent(&node_ref.left_child)
-            && is_size_consistent(&node_ref.right_child);
-    }
+        let avl_tree = source.clone();
 
-    fn is_rank_consistent<K: std::cmp::Ord, V>(avl_tree: &AVL<K, V>) -> bool {
-        for i in 0..Node::size(&avl_tree.root) {
-            if i != avl_tree.rank(avl_tree.select(i).unwrap().0) {
-                return false;
-            }
+        for i in 0..50 {
+            source.delete(&i);
         }
+        source.insert(0, 1000);
 
-        for key in avl_tree.keys() {
-            if *avl_tree.select(avl_tree.rank(key)).unwrap().0 != *key {
-                return false;
-            }
+        assert_eq!(avl_tree.size(), 100);
+        for i in 0..100 {
+            assert_eq!(*avl_tree.get(&i).unwrap(), i);
         }
 
-        true
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
     }
 
     #[test]
-    fn tree_avl_node_max_height() {
-        assert_eq!(Node::<usize, usize>::_max_height(&None, &None), -1);
-        assert_eq!(
-            Node::<usize, usize>::_max_height(&Some(Box::new(Node::init(1, 1, 0, 0))), &None),
-            0
-        );
+    fn tree_avl_nodes_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+        assert_eq!(avl_tree.nodes(), vec![]);
+
+        // inserting 0..7 in order yields a perfect tree rooted at 3
+        for i in 0..7 {
+            avl_tree.insert(i, i);
+        }
+
         assert_eq!(
-            Node::<usize, usize>::_max_height(
-                &Some(Box::new(Node::init(1, 1, 1, 0))),
-                &Some(Box::new(Node::init(1, 1, 2, 0)))
-            ),
-            2
+            avl_tree.nodes(),
+            vec![
+                (0, &3),
+                (1, &1),
+                (2, &0),
+                (2, &2),
+                (1, &5),
+                (2, &4),
+                (2, &6)
+            ]
         );
-    }
 
-    #[test]
-    fn tree_avl_node_update_height() {
-        let mut root = Node::init(1, 1, 10, 0);
-        let mut left = Node::init(1, 1, 20, 0);
-        let mut right = Node::init(1, 1, 30, 0);
+        for i in 7..100 {
+            avl_tree.insert(i, i);
+        }
 
-        left.update_height();
-        right.update_height();
+        let nodes = avl_tree.nodes();
+        assert_eq!(nodes.len(), avl_tree.size());
+        for (depth, key) in nodes {
+            assert_eq!(avl_tree.depth(key), Some(depth));
+        }
+    }
 
-        root.left_child = Some(Box::new(left));
-        root.right_child = Some(Box::new(right));
+    #[test]
+    fn tree_avl_index_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
 
-        root.update_height();
+        for i in 0..100 {
+            avl_tree.insert(i, i * 10);
+        }
 
-        assert_eq!(root.height, 1);
-        assert_eq!(root.left_child.unwrap().height, 0);
-        assert_eq!(root.right_child.unwrap().height, 0);
-    }
+        for i in 0..100 {
+            assert_eq!(avl_tree[&i], i * 10);
+        }
 
-    #[test]
-    fn tree_avl_init() {
-        let avl_tree = AVL::<usize, usize>::init();
+        for i in 0..100 {
+            avl_tree[&i] += 1;
+        }
+
+        for i in 0..100 {
+            assert_eq!(avl_tree[&i], i * 10 + 1);
+            assert_eq!(*avl_tree.get(&i).unwrap(), i * 10 + 1);
+        }
 
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
@@ -933,25 +2905,52 @@ mod tests {
     }
 
     #[test]
-    fn tree_avl_insert_1() {
+    #[should_panic(expected = "Key does not exist in the tree")]
+    fn tree_avl_panic_index() {
         let mut avl_tree = AVL::<usize, usize>::init();
+        avl_tree.insert(1, 10);
 
-        avl_tree.insert(1, 1);
+        let _ = avl_tree[&2];
+    }
 
-        assert!(is_avl(&avl_tree.root));
-        assert!(is_bst(&avl_tree.root, None, None));
-        assert!(is_size_consistent(&avl_tree.root));
-        assert!(is_rank_consistent(&avl_tree));
+    #[test]
+    #[should_panic(expected = "Key does not exist in the tree")]
+    fn tree_avl_panic_index_mut() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+        avl_tree.insert(1, 10);
+
+        avl_tree[&2] = 20;
     }
 
     #[test]
-    fn tree_avl_insert_2() {
+    fn tree_avl_range_mut_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
 
-        avl_tree.insert(4, 1);
-        avl_tree.insert(3, 1);
-        avl_tree.insert(2, 1);
-        avl_tree.insert(1, 1);
+        for i in 0..100 {
+            avl_tree.insert(i, i * 10);
+        }
+
+        let entries = avl_tree.range_mut(10..20);
+        assert_eq!(entries.len(), 10);
+        for (i, (key, value)) in entries.into_iter().enumerate() {
+            assert_eq!(*key, 10 + i);
+            *value += 1;
+        }
+
+        for i in 0..100 {
+            let expected = if (10..20).contains(&i) {
+                i * 10 + 1
+            } else {
+                i * 10
+            };
+            assert_eq!(*avl_tree.get(&i).unwrap(), expected);
+        }
+
+        assert_eq!(avl_tree.range_mut(..).len(), 100);
+        assert_eq!(avl_tree.range_mut(95..).len(), 5);
+        assert_eq!(avl_tree.range_mut(..=4).len(), 5);
+        assert_eq!(avl_tree.range_mut((Excluded(10), Included(12))).len(), 2);
+        assert_eq!(avl_tree.range_mut(200..300).len(), 0);
 
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
@@ -960,51 +2959,88 @@ mod tests {
     }
 
     #[test]
-    fn tree_avl_insert_3() {
+    fn tree_avl_lower_upper_bound_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
+        assert_eq!(avl_tree.lower_bound(&4), None);
+        assert_eq!(avl_tree.upper_bound(&4), None);
 
-        for i in (0..100).rev() {
-            avl_tree.insert(i, i);
+        for i in 0..50 {
+            avl_tree.insert(i * 2, i);
         }
 
-        assert!(is_avl(&avl_tree.root));
-        assert!(is_bst(&avl_tree.root, None, None));
-        assert!(is_size_consistent(&avl_tree.root));
-        assert!(is_rank_consistent(&avl_tree));
+        assert_eq!(avl_tree.lower_bound(&4), Some((&4, &2)));
+        assert_eq!(avl_tree.upper_bound(&4), Some((&6, &3)));
+
+        for key in 0..100 {
+            let lower = if key % 2 == 0 { key } else { key + 1 };
+            let upper = if key % 2 == 0 { key + 2 } else { key + 1 };
+
+            assert_eq!(
+                avl_tree.lower_bound(&key).map(|(k, _)| *k),
+                if lower < 100 { Some(lower) } else { None }
+            );
+            assert_eq!(
+                avl_tree.upper_bound(&key).map(|(k, _)| *k),
+                if upper < 100 { Some(upper) } else { None }
+            );
+        }
+
+        assert_eq!(avl_tree.lower_bound(&99), None);
+        assert_eq!(avl_tree.upper_bound(&98), None);
     }
 
     #[test]
-    fn tree_avl_delete_1() {
+    fn tree_avl_get_many_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
+        for i in (0..200).step_by(2) {
+            avl_tree.insert(i, i * 10);
+        }
 
-        avl_tree.delete(&1);
+        let sorted: Vec<usize> = (0..250).collect();
+        let unsorted: Vec<usize> = (0..250).map(|i| (i * 37) % 251).collect();
+        let duplicates = [4, 5, 4, 198, 199, 4];
 
-        assert!(is_avl(&avl_tree.root));
-        assert!(is_bst(&avl_tree.root, None, None));
-        assert!(is_size_consistent(&avl_tree.root));
-        assert!(is_rank_consistent(&avl_tree));
+        for keys in [&sorted[..], &unsorted[..], &duplicates[..], &[]].iter() {
+            let values = avl_tree.get_many(keys);
+
+            assert_eq!(values.len(), keys.len());
+            for (key, value) in keys.iter().zip(values) {
+                assert_eq!(value, avl_tree.get(key));
+            }
+        }
+
+        assert!(AVL::<usize, usize>::init()
+            .get_many(&[1, 2])
+            .iter()
+            .all(|v| v.is_none()));
     }
 
     #[test]
-    fn tree_avl_delete_2() {
+    fn tree_avl_levels_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
+        assert!(avl_tree.levels().is_empty());
 
-        avl_tree.insert(4, 1);
-        avl_tree.insert(3, 1);
-        avl_tree.insert(2, 1);
-        avl_tree.insert(1, 1);
-
-        avl_tree.delete(&1);
-        assert_eq!(avl_tree.get(&1), None);
+        for i in 1..=7 {
+            avl_tree.insert(i, i * 10);
+        }
 
-        avl_tree.delete(&3);
-        assert_eq!(avl_tree.get(&3), None);
+        // inserting 1..=7 in order results in a perfect tree
+        let levels = avl_tree.levels();
+        assert_eq!(levels.len() as i64, avl_tree.height() + 1);
+        assert_eq!(levels[0], vec![&4]);
+        assert_eq!(levels[1], vec![&2, &6]);
+        assert_eq!(levels[2], vec![&1, &3, &5, &7]);
 
-        avl_tree.delete(&2);
-        assert_eq!(avl_tree.get(&2), None);
+        for i in 8..100 {
+            avl_tree.insert(i, i * 10);
+        }
 
-        avl_tree.delete(&4);
-        assert_eq!(avl_tree.get(&4), None);
+        let levels = avl_tree.levels();
+        assert_eq!(levels.len() as i64, avl_tree.height() + 1);
+        assert_eq!(levels.concat(), avl_tree.keys_in_level_order());
+        for level in &levels {
+            assert!(level.windows(2).all(|pair| pair[0] < pair[1]));
+        }
 
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
@@ -1013,53 +3049,119 @@ mod tests {
     }
 
     #[test]
-    fn tree_avl_is_empty() {
+    fn tree_avl_append_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
+        for i in (0..100).step_by(2) {
+            avl_tree.insert(i, i);
+        }
 
-        assert!(avl_tree.is_empty());
+        let mut other = AVL::<usize, usize>::init();
+        for i in (0..150).step_by(3) {
+            other.insert(i, i * 10);
+        }
 
-        avl_tree.insert(1, 1);
+        avl_tree.append(&mut other);
 
-        assert!(!avl_tree.is_empty());
+        assert!(other.is_empty());
+        assert_eq!(other.size(), 0);
+
+        let expected: Vec<usize> = (0..150)
+            .filter(|i| (i % 2 == 0 && *i < 100) || i % 3 == 0)
+            .collect();
+        assert_eq!(avl_tree.size(), expected.len());
+        for key in expected {
+            let value = if key % 3 == 0 { key * 10 } else { key };
+            assert_eq!(avl_tree.get(&key), Some(&value));
+        }
+
+        // appending an empty tree and appending into an empty tree
+        avl_tree.append(&mut other);
+        other.append(&mut avl_tree);
+        assert!(avl_tree.is_empty());
+        assert_eq!(other.size(), 83);
+
+        assert!(is_avl(&other.root));
+        assert!(is_bst(&other.root, None, None));
+        assert!(is_size_consistent(&other.root));
+        assert!(is_rank_consistent(&other));
     }
 
     #[test]
-    fn tree_avl_size_1() {
-        let mut avl_tree = AVL::<usize, usize>::init();
-        assert_eq!(avl_tree.size(), 0);
+    fn tree_avl_hash_1() {
+        fn hash_of<T: Hash>(item: &T) -> u64 {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            item.hash(&mut hasher);
+            hasher.finish()
+        }
 
-        avl_tree.insert(1, 1);
-        assert_eq!(avl_tree.size(), 1);
+        let mut ascending = AVL::<usize, usize>::init();
+        let mut scattered = AVL::<usize, usize>::init();
+        for i in 0..100 {
+            ascending.insert(i, i * 10);
+            scattered.insert((i * 37) % 100, (i * 37) % 100 * 10);
+        }
 
-        avl_tree.insert(2, 1);
-        assert_eq!(avl_tree.size(), 2);
+        assert!(ascending == scattered);
+        assert_eq!(hash_of(&ascending), hash_of(&scattered));
 
-        avl_tree.insert(3, 1);
-        assert_eq!(avl_tree.size(), 3);
+        scattered.insert(50, 0);
+        assert!(ascending != scattered);
+        assert_ne!(hash_of(&ascending), hash_of(&scattered));
 
-        avl_tree.insert(4, 1);
-        assert_eq!(avl_tree.size(), 4);
+        scattered.delete(&50);
+        assert!(ascending != scattered);
+
+        assert!(AVL::<usize, usize>::init() == AVL::<usize, usize>::init());
+        assert_eq!(
+            hash_of(&AVL::<usize, usize>::init()),
+            hash_of(&AVL::<usize, usize>::init())
+        );
     }
 
     #[test]
-    fn tree_avl_contains_1() {
+    fn tree_avl_leaf_internal_count_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
-        assert!(!avl_tree.contains(&1));
+        assert_eq!(avl_tree.leaf_count(), 0);
+        assert_eq!(avl_tree.internal_count(), 0);
 
-        avl_tree.insert(1, 2);
-        assert!(avl_tree.contains(&1));
+        avl_tree.insert(1, 10);
+        assert_eq!(avl_tree.leaf_count(), 1);
+        assert_eq!(avl_tree.internal_count(), 0);
 
-        avl_tree.insert(1, 3);
-        assert!(avl_tree.contains(&1));
+        // inserting 1..=7 in order results in a complete tree
+        for i in 2..=7 {
+            avl_tree.insert(i, i * 10);
+        }
+        assert_eq!(avl_tree.leaf_count(), 4);
+        assert_eq!(avl_tree.internal_count(), 3);
+
+        for i in 8..=15 {
+            avl_tree.insert(i, i * 10);
+        }
+        assert_eq!(avl_tree.leaf_count(), 8);
+        assert_eq!(avl_tree.internal_count(), 7);
     }
 
     #[test]
-    fn tree_avl_get_1() {
-        let mut avl_tree = AVL::<usize, usize>::init();
-        assert_eq!(avl_tree.get(&1), None);
+    fn tree_avl_entry_or_default_1() {
+        let mut avl_tree: AVL<u32, Vec<u32>> = AVL::init();
 
-        avl_tree.insert(1, 2);
-        assert_eq!(*avl_tree.get(&1).unwrap(), 2);
+        // like get_or_insert, each call descends twice: once to find or insert the key and once to borrow its value by rank
+        for i in 0..300 {
+            avl_tree.entry_or_default((i * 37) % 50).push(i);
+        }
+
+        assert_eq!(avl_tree.size(), 50);
+        for key in 0..50 {
+            let expected: Vec<u32> = (0..300).filter(|i| (i * 37) % 50 == key).collect();
+            assert_eq!(*avl_tree.get(&key).unwrap(), expected);
+        }
+
+        // existing values are returned as is
+        avl_tree.entry_or_default(7).clear();
+        assert!(avl_tree.get(&7).unwrap().is_empty());
+        assert!(avl_tree.entry_or_default(100).is_empty());
+        assert_eq!(avl_tree.size(), 51);
 
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
@@ -1068,17 +3170,30 @@ mod tests {
     }
 
     #[test]
-    fn tree_avl_get_2() {
-        let mut avl_tree = AVL::<usize, usize>::init();
+    fn tree_avl_median_1() {
+        let mut avl_tree: AVL<usize, usize> = AVL::init();
+        assert_eq!(avl_tree.median(), None);
 
-        for i in (0..100).rev() {
-            avl_tree.insert(i, i);
-        }
+        for i in 0..101 {
+            avl_tree.insert((i * 37) % 101, i);
 
-        for i in (0..100).rev() {
-            assert_eq!(*avl_tree.get(&i).unwrap(), i);
+            let (key, _) = avl_tree.median().unwrap();
+            assert_eq!(avl_tree.rank(key), (avl_tree.size() - 1) / 2);
         }
 
+        // odd size: the middle key
+        assert_eq!(*avl_tree.median().unwrap().0, 50);
+
+        // even size: the lower of the two middle keys
+        avl_tree.delete(&100);
+        assert_eq!(*avl_tree.median().unwrap().0, 49);
+        avl_tree.delete(&0);
+        assert_eq!(*avl_tree.median().unwrap().0, 50);
+        avl_tree.delete(&99);
+        assert_eq!(*avl_tree.median().unwrap().0, 49);
+        avl_tree.delete(&98);
+        assert_eq!(*avl_tree.median().unwrap().0, 49);
+
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
         assert!(is_size_consistent(&avl_tree.root));
@@ -1086,24 +3201,76 @@ mod tests {
     }
 
     #[test]
-    fn tree_avl_get_3() {
-        let mut avl_tree = AVL::<usize, usize>::init();
+    fn tree_avl_values_iter_1() {
+        let mut avl_tree: AVL<usize, usize> = AVL::init();
+        assert_eq!(avl_tree.values_iter().next(), None);
 
-        for i in (0..100).rev() {
-            avl_tree.insert(i, i);
+        for i in 0..500 {
+            avl_tree.insert((i * 37) % 503, i);
         }
-
-        for i in (0..100).rev() {
-            assert_eq!(*avl_tree.get(&i).unwrap(), i);
+        for i in 0..100 {
+            avl_tree.delete(&(i * 5));
         }
 
-        for i in (0..100).rev() {
-            avl_tree.insert(i, i + 1);
+        let expected: Vec<&usize> = avl_tree
+            .keys()
+            .into_iter()
+            .map(|key| avl_tree.get(key).unwrap())
+            .collect();
+        assert_eq!(avl_tree.values_iter().collect::<Vec<&usize>>(), expected);
+        assert_eq!(avl_tree.values_iter().count(), avl_tree.size());
+    }
+
+    #[test]
+    fn tree_avl_contains_key_1() {
+        let mut avl_tree: AVL<usize, usize> = AVL::init();
+        assert!(!avl_tree.contains_key(&0));
+
+        for i in (0..100).step_by(2) {
+            avl_tree.insert(i, i);
         }
 
-        for i in (0..100).rev() {
-            assert_eq!(*avl_tree.get(&i).unwrap(), i + 1);
+        for i in 0..110 {
+            assert_eq!(avl_tree.contains_key(&i), avl_tree.contains(&i));
+            assert_eq!(avl_tree.contains_key(&i), i < 100 && i % 2 == 0);
         }
+    }
+
+    #[test]
+    fn tree_avl_swap_values_1() {
+        let mut avl_tree: AVL<usize, String> = AVL::init();
+        for i in 0..100 {
+            avl_tree.insert(i, format!("value{}", i));
+        }
+        let levels: Vec<Vec<usize>> = avl_tree
+            .levels()
+            .into_iter()
+            .map(|level| level.into_iter().copied().collect())
+            .collect();
+
+        assert!(avl_tree.swap_values(&3, &97));
+        assert_eq!(avl_tree.get(&3).unwrap(), "value97");
+        assert_eq!(avl_tree.get(&97).unwrap(), "value3");
+
+        // swapping back restores the values
+        assert!(avl_tree.swap_values(&97, &3));
+        assert_eq!(avl_tree.get(&3).unwrap(), "value3");
+
+        assert!(avl_tree.swap_values(&50, &50));
+        assert_eq!(avl_tree.get(&50).unwrap(), "value50");
+
+        // missing keys leave values untouched
+        assert!(!avl_tree.swap_values(&10, &100));
+        assert!(!avl_tree.swap_values(&100, &10));
+        assert!(!avl_tree.swap_values(&100, &100));
+        assert_eq!(avl_tree.get(&10).unwrap(), "value10");
+
+        let levels_after: Vec<Vec<usize>> = avl_tree
+            .levels()
+            .into_iter()
+            .map(|level| level.into_iter().copied().collect())
+            .collect();
+        assert_eq!(levels, levels_after);
 
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
@@ -1112,10 +3279,25 @@ mod tests {
     }
 
     #[test]
-    fn tree_avl_delete_min_1() {
-        let mut avl_tree = AVL::<usize, usize>::init();
+    fn tree_avl_get_or_insert_1() {
+        let mut avl_tree: AVL<usize, usize> = AVL::init();
 
-        avl_tree.delete_min();
+        // get_or_insert finds or inserts the key in one descent and borrows the value by rank in a second one,
+        // so every insertion rebalances the tree between the two descents and the rank must still lead to `key`
+        for i in 0..200 {
+            let key = (i * 37) % 101;
+            let (existed, value) = avl_tree.get_or_insert(key, i);
+
+            // keys repeat every 101 insertions
+            assert_eq!(existed, i >= 101);
+            assert_eq!(*value, if existed { i - 101 + 1000 } else { i });
+
+            *value += 1000;
+            let expected = *value;
+            assert_eq!(*avl_tree.get(&key).unwrap(), expected);
+        }
+
+        assert_eq!(avl_tree.size(), 101);
 
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
@@ -1124,29 +3306,60 @@ mod tests {
     }
 
     #[test]
-    fn tree_avl_delete_min_2() {
+    fn tree_avl_remove_range_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
 
-        for i in (0..100).rev() {
-            avl_tree.insert(i, i);
+        for i in 0..100 {
+            avl_tree.insert(i, i * 10);
         }
 
-        for i in 0..100 {
-            avl_tree.delete_min();
-            assert_eq!(avl_tree.get(&i), None);
+        assert_eq!(avl_tree.remove_range(25..75), 50);
+        assert_eq!(avl_tree.size(), 50);
+
+        let expected: Vec<usize> = (0..25).chain(75..100).collect();
+        assert_eq!(avl_tree.keys(), expected.iter().collect::<Vec<&usize>>());
+        for key in expected {
+            assert_eq!(*avl_tree.get(&key).unwrap(), key * 10);
         }
 
+        assert_eq!(avl_tree.remove_range(25..75), 0);
+        assert_eq!(avl_tree.remove_range((Excluded(80), Included(90))), 10);
+        assert_eq!(avl_tree.remove_range(..10), 10);
+        assert_eq!(avl_tree.size(), 30);
+
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
         assert!(is_size_consistent(&avl_tree.root));
         assert!(is_rank_consistent(&avl_tree));
+
+        assert_eq!(avl_tree.remove_range(..), 30);
+        assert!(avl_tree.is_empty());
     }
 
     #[test]
-    fn tree_avl_delete_max_1() {
+    fn tree_avl_rank_entry_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
 
-        avl_tree.delete_max();
+        for i in (1..100).rev() {
+            avl_tree.insert(i, i * 10);
+        }
+
+        for i in 1..100 {
+            let value = i * 10;
+            assert_eq!(avl_tree.rank_entry(&i), (i - 1, Some(&value)));
+            assert_eq!(
+                avl_tree.rank_entry(&i),
+                (avl_tree.rank(&i), avl_tree.get(&i))
+            );
+        }
+
+        assert_eq!(avl_tree.rank_entry(&0), (0, None));
+        assert_eq!(avl_tree.rank_entry(&100), (99, None));
+        assert_eq!(avl_tree.rank_entry(&1000), (99, None));
+
+        avl_tree.delete(&50);
+        assert_eq!(avl_tree.rank_entry(&50), (49, None));
+        assert_eq!(avl_tree.rank_entry(&51), (49, Some(&510)));
 
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
@@ -1155,58 +3368,77 @@ mod tests {
     }
 
     #[test]
-    fn tree_avl_delete_max_2() {
-        let mut avl_tree = AVL::<usize, usize>::init();
+    fn tree_avl_with_capacity_1() {
+        let mut avl_tree = AVL::<usize, usize>::with_capacity(100_000);
+        assert!(avl_tree.is_empty());
+        assert_eq!(avl_tree.capacity(), 100_000);
 
-        for i in (0..100).rev() {
-            avl_tree.insert(i, i);
+        for i in 0..100_000 {
+            avl_tree.insert((i * 7919) % 100_000, i);
         }
 
-        for i in (0..100).rev() {
-            avl_tree.delete_max();
-            assert_eq!(avl_tree.get(&i), None);
+        assert_eq!(avl_tree.size(), 100_000);
+        assert_eq!(avl_tree.capacity(), 100_000);
+        for i in 0..100_000 {
+            assert_eq!(*avl_tree.get(&((i * 7919) % 100_000)).unwrap(), i);
         }
 
         assert!(is_avl(&avl_tree.root));
         assert!(is_bst(&avl_tree.root, None, None));
         assert!(is_size_consistent(&avl_tree.root));
-        assert!(is_rank_consistent(&avl_tree));
+
+        // upsert and get_or_insert take preallocated nodes too
+        avl_tree.reserve(2);
+        avl_tree.upsert(100_000, 0, |_| {});
+        assert_eq!(avl_tree.get_or_insert(100_001, 1), (false, &mut 1));
+        assert_eq!(avl_tree.capacity(), 100_002);
+
+        // nodes beyond the reserved ones are allocated as usual
+        avl_tree.insert(100_002, 2);
+        assert_eq!(avl_tree.capacity(), 100_003);
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_size_consistent(&avl_tree.root));
     }
 
     #[test]
-    fn tree_avl_floor_1() {
+    fn tree_avl_successor_entry_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
 
         for i in (0..100).step_by(2) {
-            avl_tree.insert(i, i);
+            avl_tree.insert(i, i * 10);
         }
 
-        for i in (1..100).step_by(2) {
-            assert_eq!(*avl_tree.floor(&i).unwrap(), i - 1);
+        for i in 0..98 {
+            let successor = if i % 2 == 0 { i + 2 } else { i + 1 };
+            assert_eq!(
+                avl_tree.successor_entry(&i),
+                Some((&successor, &(successor * 10)))
+            );
         }
 
-        assert!(is_avl(&avl_tree.root));
-        assert!(is_bst(&avl_tree.root, None, None));
-        assert!(is_size_consistent(&avl_tree.root));
-        assert!(is_rank_consistent(&avl_tree));
+        assert_eq!(avl_tree.successor_entry(&98), None);
+        assert_eq!(avl_tree.successor_entry(&99), None);
+        assert_eq!(AVL::<usize, usize>::init().successor_entry(&0), None);
     }
 
     #[test]
-    fn tree_avl_ceiling_1() {
+    fn tree_avl_predecessor_entry_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
 
         for i in (0..100).step_by(2) {
-            avl_tree.insert(i, i);
+            avl_tree.insert(i, i * 10);
         }
 
-        for i in (1..99).step_by(2) {
-            assert_eq!(*avl_tree.ceiling(&i).unwrap(), i + 1);
+        for i in 1..100 {
+            let predecessor = if i % 2 == 0 { i - 2 } else { i - 1 };
+            assert_eq!(
+                avl_tree.predecessor_entry(&i),
+                Some((&predecessor, &(predecessor * 10)))
+            );
         }
 
-        assert!(is_avl(&avl_tree.root));
-        assert!(is_bst(&avl_tree.root, None, None));
-        assert!(is_size_consistent(&avl_tree.root));
-        assert!(is_rank_consistent(&avl_tree));
+        assert_eq!(avl_tree.predecessor_entry(&0), None);
+        assert_eq!(AVL::<usize, usize>::init().predecessor_entry(&0), None);
     }
 
     #[test]
@@ -1266,6 +3498,102 @@ mod tests {
         assert!(is_rank_consistent(&avl_tree));
     }
 
+    #[test]
+    fn tree_avl_count_values_less_than_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+        assert_eq!(avl_tree.count_values_less_than(&10), 0);
+
+        for i in (0..100).rev() {
+            avl_tree.insert(i, i * 2);
+        }
+
+        // values are 0, 2, 4, ..., 198
+        assert_eq!(avl_tree.count_values_less_than(&0), 0);
+        assert_eq!(avl_tree.count_values_less_than(&1), 1);
+        assert_eq!(avl_tree.count_values_less_than(&50), 25);
+        assert_eq!(avl_tree.count_values_less_than(&51), 26);
+        assert_eq!(avl_tree.count_values_less_than(&1000), 100);
+    }
+
+    #[test]
+    fn tree_avl_split_off_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in (0..100).rev() {
+            avl_tree.insert(i, i);
+        }
+
+        let higher_tree = avl_tree.split_off(&50);
+
+        assert_eq!(avl_tree.size(), 50);
+        assert_eq!(higher_tree.size(), 50);
+
+        assert_eq!(avl_tree.min().unwrap(), (&0, &0));
+        assert_eq!(avl_tree.max().unwrap(), (&49, &49));
+        assert_eq!(higher_tree.min().unwrap(), (&50, &50));
+        assert_eq!(higher_tree.max().unwrap(), (&99, &99));
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+
+        assert!(is_avl(&higher_tree.root));
+        assert!(is_bst(&higher_tree.root, None, None));
+        assert!(is_size_consistent(&higher_tree.root));
+        assert!(is_rank_consistent(&higher_tree));
+    }
+
+    #[test]
+    fn tree_avl_split_off_2() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in 0..10 {
+            avl_tree.insert(i, i);
+        }
+
+        let higher_tree = avl_tree.split_off(&100);
+        assert_eq!(avl_tree.size(), 10);
+        assert!(higher_tree.is_empty());
+
+        let higher_tree = avl_tree.split_off(&0);
+        assert!(avl_tree.is_empty());
+        assert_eq!(higher_tree.size(), 10);
+
+        assert!(is_avl(&higher_tree.root));
+        assert!(is_bst(&higher_tree.root, None, None));
+        assert!(is_size_consistent(&higher_tree.root));
+        assert!(is_rank_consistent(&higher_tree));
+    }
+
+    #[test]
+    fn tree_avl_extract_if_1() {
+        let mut avl_tree = AVL::<usize, usize>::init();
+
+        for i in 0..100 {
+            avl_tree.insert(i, i);
+        }
+
+        let extracted = avl_tree.extract_if(|key, _| key % 2 == 0);
+
+        assert_eq!(
+            extracted,
+            (0..100)
+                .step_by(2)
+                .map(|i| (i, i))
+                .collect::<Vec<(usize, usize)>>()
+        );
+
+        let odd_keys: Vec<usize> = (1..100).step_by(2).collect();
+        assert_eq!(avl_tree.size(), 50);
+        assert_eq!(avl_tree.keys(), odd_keys.iter().collect::<Vec<&usize>>());
+
+        assert!(is_avl(&avl_tree.root));
+        assert!(is_bst(&avl_tree.root, None, None));
+        assert!(is_size_consistent(&avl_tree.root));
+        assert!(is_rank_consistent(&avl_tree));
+    }
+
     #[test]
     fn tree_avl_keys_between_1() {
         let mut avl_tree = AVL::<usize, usize>::init();
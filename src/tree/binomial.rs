@@ -20,7 +20,7 @@
 /// assert_eq!(merged_tree.rank(), 1);
 /// ```
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BinomialTree<T: std::cmp::Ord> {
     // rank of the tree
     rank: usize,
@@ -286,6 +286,45 @@ impl<T: std::cmp::Ord> BinomialTree<T> {
     pub fn children(&self) -> &Vec<Option<BinomialTree<T>>> {
         &self.children
     }
+
+    /// Returns an iterator over payloads of the tree in a preorder fashion.
+    /// nodes whose payload has been taken are skipped
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::BinomialTree;
+    ///
+    /// let bt1 = BinomialTree::init_min(0);
+    /// let bt2 = BinomialTree::init_min(1);
+    /// let merged_tree_1 = BinomialTree::merge(bt1, bt2);
+    ///
+    /// let bt3 = BinomialTree::init_min(2);
+    /// let bt4 = BinomialTree::init_min(3);
+    /// let merged_tree_2 = BinomialTree::merge(bt3, bt4);
+    ///
+    /// let merged_tree = BinomialTree::merge(merged_tree_1, merged_tree_2);
+    ///
+    /// assert_eq!(merged_tree.iter_preorder().collect::<Vec<&usize>>(), vec![&0, &1, &2, &3]);
+    /// ```
+    pub fn iter_preorder(&self) -> impl Iterator<Item = &T> {
+        // nodes yet to be visited. the next node to visit is at the top of the stack
+        let mut stack: Vec<&BinomialTree<T>> = vec![self];
+
+        std::iter::from_fn(move || {
+            while let Some(node) = stack.pop() {
+                // push children in reverse so they are visited from left to right
+                for child in node.children.iter().rev().flatten() {
+                    stack.push(child);
+                }
+
+                if let Some(payload) = node.peek_payload() {
+                    return Some(payload);
+                }
+            }
+
+            None
+        })
+    }
 }
 
 impl<T: std::cmp::Ord + std::fmt::Display> BinomialTree<T> {
@@ -558,4 +597,30 @@ mod tests {
         bt1.get_payload();
         bt1.get_payload();
     }
+
+    #[test]
+    fn tree_binomial_iter_preorder() {
+        let mut trees: Vec<BinomialTree<usize>> = (0..8).map(BinomialTree::init_max).collect();
+
+        // merge trees pairwise until one tree of rank 3 remains
+        while trees.len() > 1 {
+            let mut merged_trees = Vec::new();
+            while !trees.is_empty() {
+                let bt1 = trees.remove(0);
+                let bt2 = trees.remove(0);
+                merged_trees.push(BinomialTree::merge(bt1, bt2));
+            }
+            trees = merged_trees;
+        }
+
+        let bt = trees.pop().unwrap();
+        let preorder = BinomialTree::preorder(&bt);
+
+        assert_eq!(
+            bt.iter_preorder()
+                .map(|payload| payload.to_string())
+                .collect::<Vec<String>>(),
+            preorder.split(' ').collect::<Vec<&str>>()
+        );
+    }
 }
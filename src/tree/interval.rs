@@ -194,6 +194,38 @@ impl<T: Ord> IntervalTree<T> {
         IntervalTree { root: None }
     }
 
+    /// Initializes an interval tree out of `intervals` after merging all overlapping and adjacent intervals.
+    /// Therefore no two intervals in the resulting tree overlap
+    ///
+    /// # Arguments
+    /// * `intervals`: intervals to be merged and inserted into the tree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let interval_tree = IntervalTree::from_merged(vec![
+    ///     Interval::new(Included(2), Included(5)),
+    ///     Interval::new(Included(1), Included(3)),
+    ///     Interval::new(Included(7), Included(8)),
+    /// ]);
+    ///
+    /// assert_eq!(interval_tree.size(), 2);
+    /// assert!(interval_tree.min().unwrap() == Interval::new(Included(1), Included(5)));
+    /// assert!(interval_tree.max().unwrap() == Interval::new(Included(7), Included(8)));
+    /// ```
+    pub fn from_merged(intervals: Vec<Interval<T>>) -> IntervalTree<T> {
+        let mut interval_tree = IntervalTree::init();
+
+        for interval in Interval::normalize(intervals) {
+            interval_tree.insert(interval);
+        }
+
+        interval_tree
+    }
+
     /// Returns true if there are no intervals in the tree, false otherwise
     pub fn is_empty(&self) -> bool {
         self.root.is_none()
@@ -301,6 +333,58 @@ impl<T: Ord> IntervalTree<T> {
         }
     }
 
+    /// Returns true if there exists an interval in the tree that contains the point `value`
+    ///
+    /// # Arguments
+    /// * `value`: point to be searched for any overlaps
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+    /// interval_tree.insert(Interval::new(Included(6), Included(10)));
+    ///
+    /// assert!(interval_tree.overlaps_point(&6));
+    /// assert!(!interval_tree.overlaps_point(&3));
+    /// ```
+    pub fn overlaps_point(&self, value: &T) -> bool
+    where
+        T: Clone,
+    {
+        self.find_overlap_point(value).is_some()
+    }
+
+    /// Returns first interval that contains the point `value`
+    ///
+    /// # Arguments
+    /// * `value`: point to be searched for any overlaps
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+    /// interval_tree.insert(Interval::new(Included(6), Included(10)));
+    ///
+    /// assert!(interval_tree.find_overlap_point(&8).unwrap() == Interval::new(Included(6), Included(10)));
+    /// assert!(interval_tree.find_overlap_point(&4).is_none());
+    /// ```
+    pub fn find_overlap_point(&self, value: &T) -> Option<Interval<T>>
+    where
+        T: Clone,
+    {
+        self.find_overlap(&Interval::point(value.clone()))
+    }
+
     /// Returns all intervals that overlap with the specified `interval`
     ///
     /// # Arguments
@@ -363,6 +447,325 @@ impl<T: Ord> IntervalTree<T> {
         IntervalTree::_find_overlaps(&node_ref.right_child, interval, overlaps);
     }
 
+    /// Returns all intervals in the tree that are contained in `query`
+    ///
+    /// # Arguments
+    /// * `query`: interval that must contain the returned intervals
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+    /// interval_tree.insert(Interval::new(Included(4), Included(6)));
+    /// interval_tree.insert(Interval::new(Included(5), Included(10)));
+    ///
+    /// let contained = interval_tree.find_contained(&Interval::new(Included(0), Included(8)));
+    ///
+    /// assert_eq!(contained.len(), 2);
+    /// assert!(contained.contains(&Interval::new(Included(0), Excluded(3))));
+    /// assert!(contained.contains(&Interval::new(Included(4), Included(6))));
+    /// ```
+    pub fn find_contained(&self, query: &Interval<T>) -> Vec<Interval<T>> {
+        let mut contained = Vec::<Interval<T>>::new();
+
+        IntervalTree::_find_overlaps_matching(
+            &self.root,
+            query,
+            &|interval| Interval::contains(query, interval),
+            &mut contained,
+        );
+
+        contained
+    }
+
+    /// Returns all intervals in the tree that contain `query`
+    ///
+    /// # Arguments
+    /// * `query`: interval that must be contained in the returned intervals
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+    /// interval_tree.insert(Interval::new(Included(4), Included(6)));
+    /// interval_tree.insert(Interval::new(Included(5), Included(10)));
+    ///
+    /// let containing = interval_tree.find_containing(&Interval::new(Included(5), Included(6)));
+    ///
+    /// assert_eq!(containing.len(), 2);
+    /// assert!(containing.contains(&Interval::new(Included(4), Included(6))));
+    /// assert!(containing.contains(&Interval::new(Included(5), Included(10))));
+    /// ```
+    pub fn find_containing(&self, query: &Interval<T>) -> Vec<Interval<T>> {
+        let mut containing = Vec::<Interval<T>>::new();
+
+        IntervalTree::_find_overlaps_matching(
+            &self.root,
+            query,
+            &|interval| Interval::contains(interval, query),
+            &mut containing,
+        );
+
+        containing
+    }
+
+    // finds intervals that overlap with `interval` and satisfy `matches`.
+    // contained and containing intervals both overlap with the query, so subtrees without overlaps are skipped
+    fn _find_overlaps_matching<F>(
+        node: &Option<Box<Node<T>>>,
+        interval: &Interval<T>,
+        matches: &F,
+        overlaps: &mut Vec<Interval<T>>,
+    ) where
+        F: Fn(&Interval<T>) -> bool,
+    {
+        if node.is_none() {
+            return;
+        }
+        let node_ref = node.as_ref().unwrap();
+        if Interval::overlaps(node_ref.interval(), interval) && matches(node_ref.interval()) {
+            overlaps.push(node_ref.interval().duplicate());
+        }
+
+        if node_ref.left_child.is_some()
+            && Node::is_ge(
+                node_ref.left_child.as_ref().unwrap().get_max(),
+                interval.get_low(),
+            )
+        {
+            IntervalTree::_find_overlaps_matching(
+                &node_ref.left_child,
+                interval,
+                matches,
+                overlaps,
+            );
+        }
+        IntervalTree::_find_overlaps_matching(&node_ref.right_child, interval, matches, overlaps);
+    }
+
+    /// Returns the interval in the tree with the smallest gap to `query`.
+    /// If an interval overlaps with `query`, its gap is zero and it is returned.
+    /// If the nearest intervals below and above `query` are at the same distance, the one below is returned
+    ///
+    /// # Arguments
+    /// * `query`: interval to find the nearest interval to
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+    /// interval_tree.insert(Interval::new(Included(10), Included(12)));
+    ///
+    /// // [5,6] is 2 away from [0,3) and 4 away from [10,12]
+    /// let nearest = interval_tree.nearest(&Interval::new(Included(5), Included(6)));
+    /// assert!(nearest.unwrap() == Interval::new(Included(0), Excluded(3)));
+    ///
+    /// let nearest = interval_tree.nearest(&Interval::point(11));
+    /// assert!(nearest.unwrap() == Interval::new(Included(10), Included(12)));
+    /// ```
+    pub fn nearest(&self, query: &Interval<T>) -> Option<Interval<T>>
+    where
+        T: Clone + std::ops::Sub<Output = T>,
+    {
+        if let Some(overlap) = self.find_overlap(query) {
+            return Some(overlap);
+        }
+
+        // no interval overlaps with `query`, so each interval lies either entirely below or entirely above it
+        let below = IntervalTree::_nearest_below(&self.root, query);
+        let above = IntervalTree::_nearest_above(&self.root, query);
+
+        match (below, above) {
+            (Some(below), Some(above)) => {
+                if IntervalTree::_gap(below, query) <= IntervalTree::_gap(query, above) {
+                    Some(below.duplicate())
+                } else {
+                    Some(above.duplicate())
+                }
+            }
+            (Some(below), None) => Some(below.duplicate()),
+            (None, Some(above)) => Some(above.duplicate()),
+            (None, None) => None,
+        }
+    }
+
+    // among intervals less than `query`, finds the one with the greatest high end
+    fn _nearest_below<'a>(
+        node: &'a Option<Box<Node<T>>>,
+        query: &Interval<T>,
+    ) -> Option<&'a Interval<T>> {
+        // node whose interval and left subtree hold the greatest high end found so far
+        let mut nearest: Option<&Box<Node<T>>> = None;
+        let mut nearest_max: Option<Rc<Bound<T>>> = None;
+
+        let mut current = node;
+        while let Some(node_ref) = current {
+            if *node_ref.interval() < *query {
+                let mut max = node_ref.interval().get_high();
+                if let Some(left_child) = &node_ref.left_child {
+                    max = Node::find_max(max, left_child.get_max());
+                }
+
+                if nearest_max.is_none()
+                    || Node::find_max(Rc::clone(&max), nearest_max.clone().unwrap()) == max
+                {
+                    nearest = Some(node_ref);
+                    nearest_max = Some(max);
+                }
+
+                current = &node_ref.right_child;
+            } else {
+                current = &node_ref.left_child;
+            }
+        }
+
+        let nearest = nearest?;
+        let nearest_max = nearest_max.unwrap();
+
+        match &nearest.left_child {
+            Some(left_child) if left_child.get_max() == nearest_max => {
+                Some(IntervalTree::_find_by_max(left_child))
+            }
+            _ => Some(nearest.interval()),
+        }
+    }
+
+    // finds the interval in the subtree rooted at `node` whose high end is the max of the subtree
+    fn _find_by_max(node: &Node<T>) -> &Interval<T> {
+        let max = node.get_max();
+
+        let mut current = node;
+        loop {
+            match &current.left_child {
+                Some(left_child) if left_child.get_max() == max => current = left_child.as_ref(),
+                _ if current.interval().get_high() == max => return current.interval(),
+                _ => current = current.right_child.as_ref().unwrap().as_ref(),
+            }
+        }
+    }
+
+    // among intervals greater than `query`, finds the one with the lowest low end
+    fn _nearest_above<'a>(
+        node: &'a Option<Box<Node<T>>>,
+        query: &Interval<T>,
+    ) -> Option<&'a Interval<T>> {
+        let mut nearest = None;
+
+        let mut current = node;
+        while let Some(node_ref) = current {
+            if *node_ref.interval() > *query {
+                nearest = Some(node_ref.interval());
+                current = &node_ref.left_child;
+            } else {
+                current = &node_ref.right_child;
+            }
+        }
+
+        nearest
+    }
+
+    // distance between high end of `lower` and low end of `higher`, which do not overlap
+    fn _gap(lower: &Interval<T>, higher: &Interval<T>) -> T
+    where
+        T: Clone + std::ops::Sub<Output = T>,
+    {
+        match (lower.high(), higher.low()) {
+            (Included(high), Included(low))
+            | (Included(high), Excluded(low))
+            | (Excluded(high), Included(low))
+            | (Excluded(high), Excluded(low)) => low.clone() - high.clone(),
+            _ => panic!("Intervals that do not overlap must be bounded where they face each other"),
+        }
+    }
+
+    /// Returns the parts of `within` that are not covered by any interval in the tree, sorted from lowest to highest.
+    /// Overlapping and adjacent intervals in the tree are merged before looking for the gaps between them
+    ///
+    /// # Arguments
+    /// * `within`: interval to be searched for uncovered parts
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(1), Included(3)));
+    /// interval_tree.insert(Interval::new(Included(5), Included(7)));
+    ///
+    /// // gaps are: [0,1), (3,5), (7,10]
+    /// let gaps = interval_tree.gaps(&Interval::new(Included(0), Included(10)));
+    ///
+    /// assert!(gaps[0] == Interval::new(Included(0), Excluded(1)));
+    /// assert!(gaps[1] == Interval::new(Excluded(3), Excluded(5)));
+    /// assert!(gaps[2] == Interval::new(Excluded(7), Included(10)));
+    /// ```
+    pub fn gaps(&self, within: &Interval<T>) -> Vec<Interval<T>>
+    where
+        T: Clone,
+    {
+        // parts of `within` that are covered by the tree
+        let covered: Vec<Interval<T>> = self
+            .find_overlaps(within)
+            .iter()
+            .filter_map(|interval| Interval::get_overlap(interval, within))
+            .collect();
+        let merged = Interval::normalize(covered);
+
+        // sweep from the low end of `within` and emit the space before each covered interval
+        let mut gaps = Vec::new();
+        let mut low = within.low().clone();
+        for interval in &merged {
+            if low != *interval.low() {
+                gaps.push(Interval::new(
+                    low,
+                    IntervalTree::_complement(interval.low()),
+                ));
+            }
+
+            low = IntervalTree::_complement(interval.high());
+        }
+
+        let covers_high_end = match merged.last() {
+            Some(last) => last.high() == within.high(),
+            None => false,
+        };
+        if !covers_high_end {
+            gaps.push(Interval::new(low, within.high().clone()));
+        }
+
+        gaps
+    }
+
+    // returns the bound that starts exactly where `bound` ends. ex. the complement of [3 is 3)
+    fn _complement(bound: &Bound<T>) -> Bound<T>
+    where
+        T: Clone,
+    {
+        match bound {
+            Included(value) => Excluded(value.clone()),
+            Excluded(value) => Included(value.clone()),
+            Unbounded => Unbounded,
+        }
+    }
+
     /// Inserts an interval in the tree. if interval already exists, `interval` will be ignored
     ///
     /// # Arguments
@@ -584,7 +987,7 @@ impl<T: Ord> IntervalTree<T> {
 
         node.update_height();
         node.update_size();
-        node.update_size();
+        node.update_max();
 
         Some(IntervalTree::balance(node))
     }
@@ -803,6 +1206,43 @@ impl<T: Ord> IntervalTree<T> {
         IntervalTree::_intervals_in_order(&node_ref.right_child, intervals);
     }
 
+    /// Returns a lazy iterator over references to the intervals in the tree following an in-order traversal.
+    /// Therefore intervals are yielded from smallest to largest.
+    /// Unlike `intervals`, nothing is duplicated and only the path to the next node is kept: O(height) space
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Excluded(5), Included(8)));
+    /// interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+    /// interval_tree.insert(Interval::new(Included(6), Included(10)));
+    ///
+    /// let intervals: Vec<String> = interval_tree.iter().map(|interval| format!("{}", interval)).collect();
+    /// assert_eq!(intervals, vec!["[0,3)", "(5,8]", "[6,10]"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        // nodes whose left subtree is visited but they themselves are not
+        let mut stack: Vec<&Node<T>> = Vec::new();
+        let mut current = self.root.as_deref();
+
+        std::iter::from_fn(move || {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left_child.as_deref();
+            }
+
+            let node = stack.pop()?;
+            current = node.right_child.as_deref();
+
+            Some(node.interval())
+        })
+    }
+
     /// Returns the number of intervals in the tree less than `interval`
     ///
     /// # Arguments
@@ -886,6 +1326,182 @@ impl<T: Ord> IntervalTree<T> {
 
         return self.rank(high_bound) - self.rank(low_bound) + 1;
     }
+
+    /// Returns the number of intervals in the tree which are greater than or equal to `low_bound`
+    /// and less than `high_bound`, or less than or equal to it if `inclusive_high` is true
+    ///
+    /// # Arguments
+    /// * `low_bound`: lowest interval of the range, always included
+    /// * `high_bound`: highest interval of the range
+    /// * `inclusive_high`: whether intervals equal to `high_bound` are counted
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+    /// interval_tree.insert(Interval::new(Excluded(5), Included(8)));
+    /// interval_tree.insert(Interval::new(Included(6), Included(10)));
+    /// interval_tree.insert(Interval::new(Excluded(8), Included(9)));
+    ///
+    /// let low = Interval::new(Included(0), Excluded(3));
+    /// let high = Interval::new(Included(6), Included(10));
+    /// assert_eq!(interval_tree.count_between(&low, &high, false), 2);
+    /// assert_eq!(interval_tree.count_between(&low, &high, true), 3);
+    /// ```
+    pub fn count_between(
+        &self,
+        low_bound: &Interval<T>,
+        high_bound: &Interval<T>,
+        inclusive_high: bool,
+    ) -> usize {
+        let below_high = IntervalTree::_count_below(&self.root, high_bound, inclusive_high);
+        let below_low = IntervalTree::_count_below(&self.root, low_bound, false);
+
+        below_high.saturating_sub(below_low)
+    }
+
+    // number of intervals less than `interval`, or less than or equal to it if `inclusive` is true
+    fn _count_below(node: &Option<Box<Node<T>>>, interval: &Interval<T>, inclusive: bool) -> usize {
+        if node.is_none() {
+            return 0;
+        }
+        let node_ref = node.as_ref().unwrap();
+
+        if *node_ref.interval() < *interval || (inclusive && *node_ref.interval() == *interval) {
+            1 + Node::size(&node_ref.left_child)
+                + IntervalTree::_count_below(&node_ref.right_child, interval, inclusive)
+        } else {
+            IntervalTree::_count_below(&node_ref.left_child, interval, inclusive)
+        }
+    }
+
+    /// Returns the maximum number of intervals in the tree that overlap at a single point.
+    /// Intervals are swept in order of their low bounds while a second cursor over intervals sorted by their high bounds
+    /// skips the ones that end before the current interval starts
+    /// * Complexity: O(n log(n))
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(1), Included(5)));
+    /// interval_tree.insert(Interval::new(Included(2), Included(6)));
+    /// interval_tree.insert(Interval::new(Included(4), Included(7)));
+    /// interval_tree.insert(Interval::new(Included(8), Included(9)));
+    ///
+    /// // [1,5], [2,6] and [4,7] all overlap at 4
+    /// assert_eq!(interval_tree.max_overlap_count(), 3);
+    /// ```
+    pub fn max_overlap_count(&self) -> usize {
+        let mut by_high: Vec<&Interval<T>> = self.iter().collect();
+        by_high.sort_by(|first, second| first.cmp_by_high(second));
+
+        let mut max_count = 0;
+        let mut ended = 0;
+        for (started, interval) in self.iter().enumerate() {
+            // intervals that end before the current one starts can not overlap it or any interval after it
+            while ended < by_high.len()
+                && IntervalTree::_ends_before(by_high[ended].high(), interval.low())
+            {
+                ended += 1;
+            }
+
+            // remaining intervals that started so far pairwise overlap, so they share a common point
+            max_count = std::cmp::max(max_count, started + 1 - ended);
+        }
+
+        max_count
+    }
+
+    // returns true if an interval with `high` bound ends before an interval with `low` bound starts
+    fn _ends_before(high: &Bound<T>, low: &Bound<T>) -> bool {
+        match (high, low) {
+            (Included(high), Included(low)) => high < low,
+            (Included(high), Excluded(low))
+            | (Excluded(high), Included(low))
+            | (Excluded(high), Excluded(low)) => high <= low,
+            _ => false,
+        }
+    }
+
+    /// Returns true if all invariants of the tree hold, false otherwise. Checked invariants are:
+    /// * intervals are ordered as a binary search tree
+    /// * heights are consistent and the tree is balanced as an AVL tree
+    /// * sizes are consistent
+    /// * cached max of each node is the highest bound of the intervals in its subtree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::IntervalTree;
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut interval_tree = IntervalTree::<usize>::init();
+    ///
+    /// interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+    /// interval_tree.insert(Interval::new(Excluded(5), Included(8)));
+    /// interval_tree.insert(Interval::new(Included(6), Included(10)));
+    /// interval_tree.delete_min();
+    ///
+    /// assert!(interval_tree.validate());
+    /// ```
+    pub fn validate(&self) -> bool {
+        IntervalTree::_validate(&self.root, None, None)
+    }
+
+    // checks invariants of the subtree rooted at `node`. all intervals in the subtree must be between `low` and `high`(exclusive)
+    fn _validate(
+        node: &Option<Box<Node<T>>>,
+        low: Option<&Interval<T>>,
+        high: Option<&Interval<T>>,
+    ) -> bool {
+        if node.is_none() {
+            return true;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        let interval = node_ref.interval();
+
+        // Option::is_some_and would need rust 1.70
+        #[allow(clippy::unnecessary_map_or)]
+        let out_of_bounds = low.map_or(false, |low| *interval <= *low)
+            || high.map_or(false, |high| *interval >= *high);
+        if out_of_bounds {
+            return false;
+        }
+
+        // cached values of children can be relied on only after children are validated
+        if !IntervalTree::_validate(&node_ref.left_child, low, Some(interval))
+            || !IntervalTree::_validate(&node_ref.right_child, Some(interval), high)
+        {
+            return false;
+        }
+
+        let height = 1 + Node::_max_height(&node_ref.left_child, &node_ref.right_child);
+        let size = 1 + Node::size(&node_ref.left_child) + Node::size(&node_ref.right_child);
+
+        let max = node_ref
+            .left_child
+            .iter()
+            .chain(node_ref.right_child.iter())
+            .fold(interval.get_high(), |max, child| {
+                Node::find_max(max, child.get_max())
+            });
+
+        node_ref.height as i64 == height
+            && Node::balance_factor(node_ref).abs() <= 1
+            && node_ref.size == size
+            && *node_ref.get_max() == *max
+    }
 }
 
 impl<T: Debug + Ord> Debug for IntervalTree<T> {
@@ -1391,4 +2007,428 @@ mod tests {
         assert_eq!(format!("{:?}", &interval_tree),
             "IntervalTree {Interval { low: Excluded(0), high: Included(1) }}");
     }
+
+    #[test]
+    fn tree_interval_from_merged_1() {
+        let interval_tree = IntervalTree::from_merged(vec![
+            Interval::new(Included(7), Included(8)),
+            Interval::new(Included(1), Included(3)),
+            Interval::new(Included(2), Included(5)),
+        ]);
+
+        assert_eq!(interval_tree.size(), 2);
+        assert_eq!(
+            interval_tree.intervals(),
+            vec![
+                Interval::new(Included(1), Included(5)),
+                Interval::new(Included(7), Included(8))
+            ]
+        );
+    }
+
+    #[test]
+    fn tree_interval_from_merged_2() {
+        let interval_tree = IntervalTree::<usize>::from_merged(vec![]);
+        assert!(interval_tree.is_empty());
+
+        let interval_tree = IntervalTree::from_merged(vec![
+            Interval::new(Included(0), Excluded(3)),
+            Interval::new(Included(3), Excluded(5)),
+            Interval::new(Excluded(5), Included(6)),
+            Interval::new(Included(1), Included(2)),
+        ]);
+
+        assert_eq!(
+            interval_tree.intervals(),
+            vec![
+                Interval::new(Included(0), Excluded(5)),
+                Interval::new(Excluded(5), Included(6))
+            ]
+        );
+    }
+
+    #[test]
+    fn tree_interval_find_overlap_point_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+
+        interval_tree.insert(Interval::new(Excluded(0), Included(1)));
+        interval_tree.insert(Interval::new(Included(6), Included(10)));
+        interval_tree.insert(Interval::new(Excluded(15), Excluded(23)));
+        interval_tree.insert(Interval::new(Excluded(25), Included(30)));
+
+        assert!(
+            interval_tree.find_overlap_point(&1).unwrap()
+                == Interval::new(Excluded(0), Included(1))
+        );
+        assert!(
+            interval_tree.find_overlap_point(&16).unwrap()
+                == Interval::new(Excluded(15), Excluded(23))
+        );
+        assert!(interval_tree.overlaps_point(&30));
+
+        for value in [0, 3, 11, 15, 23, 25, 31].iter() {
+            assert!(interval_tree.find_overlap_point(value).is_none());
+            assert!(!interval_tree.overlaps_point(value));
+        }
+    }
+
+    #[test]
+    fn tree_interval_gaps_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+
+        interval_tree.insert(Interval::new(Included(1), Included(3)));
+        interval_tree.insert(Interval::new(Included(5), Included(7)));
+
+        assert_eq!(
+            interval_tree.gaps(&Interval::new(Included(0), Included(10))),
+            vec![
+                Interval::new(Included(0), Excluded(1)),
+                Interval::new(Excluded(3), Excluded(5)),
+                Interval::new(Excluded(7), Included(10))
+            ]
+        );
+    }
+
+    #[test]
+    fn tree_interval_gaps_2() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+
+        let within = Interval::new(Included(0), Excluded(20));
+        assert_eq!(interval_tree.gaps(&within), vec![within.duplicate()]);
+
+        interval_tree.insert(Interval::new(Unbounded, Excluded(2)));
+        interval_tree.insert(Interval::new(Included(1), Included(4)));
+        interval_tree.insert(Interval::new(Excluded(4), Excluded(6)));
+        interval_tree.insert(Interval::new(Included(8), Included(8)));
+        interval_tree.insert(Interval::new(Included(10), Included(12)));
+        interval_tree.insert(Interval::new(Excluded(11), Included(14)));
+        interval_tree.insert(Interval::new(Included(18), Unbounded));
+        interval_tree.insert(Interval::new(Included(30), Included(40)));
+
+        assert_eq!(
+            interval_tree.gaps(&within),
+            vec![
+                Interval::new(Included(6), Excluded(8)),
+                Interval::new(Excluded(8), Excluded(10)),
+                Interval::new(Excluded(14), Excluded(18))
+            ]
+        );
+
+        assert_eq!(
+            interval_tree.gaps(&Interval::new(Included(2), Included(5))),
+            vec![]
+        );
+        assert_eq!(
+            interval_tree.gaps(&Interval::new(Included(7), Excluded(8))),
+            vec![Interval::new(Included(7), Excluded(8))]
+        );
+    }
+
+    #[test]
+    fn tree_interval_validate_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+        assert!(interval_tree.validate());
+
+        for i in 0..200 {
+            let low = (i * 37) % 101;
+            let high = low + (i * 13) % 17;
+            interval_tree.insert(Interval::new(Included(low), Excluded(high + 1)));
+            interval_tree.insert(Interval::new(Excluded(low), Unbounded));
+        }
+        assert!(interval_tree.validate());
+
+        for i in 0..300 {
+            match i % 3 {
+                0 => interval_tree.delete_min(),
+                1 => interval_tree.delete_max(),
+                _ => {
+                    let low = (i * 37) % 101;
+                    let high = low + (i * 13) % 17;
+                    interval_tree.delete(&Interval::new(Included(low), Excluded(high + 1)));
+                }
+            }
+
+            assert!(interval_tree.validate());
+        }
+
+        for i in 0..50 {
+            interval_tree.insert(Interval::new(Included(i), Included(i * 2)));
+        }
+        assert!(interval_tree.validate());
+    }
+
+    #[test]
+    fn tree_interval_validate_2() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+
+        for i in 0..10 {
+            interval_tree.insert(Interval::new(Included(i), Included(i + 5)));
+        }
+        assert!(interval_tree.validate());
+
+        // a stale max must be detected
+        interval_tree.root.as_mut().unwrap().max = Some(Rc::new(Included(0)));
+        assert!(!interval_tree.validate());
+    }
+
+    #[test]
+    fn tree_interval_find_contained_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+
+        interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+        interval_tree.insert(Interval::new(Included(6), Included(10)));
+        interval_tree.insert(Interval::new(Excluded(8), Included(9)));
+        interval_tree.insert(Interval::new(Excluded(15), Excluded(23)));
+        interval_tree.insert(Interval::new(Included(16), Excluded(21)));
+        interval_tree.insert(Interval::new(Included(17), Excluded(19)));
+        interval_tree.insert(Interval::new(Excluded(19), Included(20)));
+        interval_tree.insert(Interval::new(Excluded(25), Included(30)));
+        interval_tree.insert(Interval::new(Included(26), Included(26)));
+
+        let mut contained = interval_tree.find_contained(&Interval::new(Included(8), Included(21)));
+        contained.sort();
+        assert_eq!(
+            contained,
+            vec![
+                Interval::new(Excluded(8), Included(9)),
+                Interval::new(Included(16), Excluded(21)),
+                Interval::new(Included(17), Excluded(19)),
+                Interval::new(Excluded(19), Included(20))
+            ]
+        );
+
+        let mut contained = interval_tree.find_contained(&Interval::new(Excluded(16), Unbounded));
+        contained.sort();
+        assert_eq!(
+            contained,
+            vec![
+                Interval::new(Included(17), Excluded(19)),
+                Interval::new(Excluded(19), Included(20)),
+                Interval::new(Excluded(25), Included(30)),
+                Interval::new(Included(26), Included(26))
+            ]
+        );
+
+        assert!(interval_tree
+            .find_contained(&Interval::new(Included(11), Included(15)))
+            .is_empty());
+    }
+
+    #[test]
+    fn tree_interval_find_containing_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+
+        interval_tree.insert(Interval::new(Included(0), Excluded(3)));
+        interval_tree.insert(Interval::new(Included(6), Included(10)));
+        interval_tree.insert(Interval::new(Excluded(8), Included(9)));
+        interval_tree.insert(Interval::new(Excluded(15), Excluded(23)));
+        interval_tree.insert(Interval::new(Included(16), Excluded(21)));
+        interval_tree.insert(Interval::new(Included(17), Excluded(19)));
+        interval_tree.insert(Interval::new(Excluded(19), Included(20)));
+        interval_tree.insert(Interval::new(Excluded(25), Included(30)));
+        interval_tree.insert(Interval::new(Included(26), Included(26)));
+
+        let mut containing = interval_tree.find_containing(&Interval::point(18));
+        containing.sort();
+        assert_eq!(
+            containing,
+            vec![
+                Interval::new(Excluded(15), Excluded(23)),
+                Interval::new(Included(16), Excluded(21)),
+                Interval::new(Included(17), Excluded(19))
+            ]
+        );
+
+        let mut containing =
+            interval_tree.find_containing(&Interval::new(Excluded(8), Included(9)));
+        containing.sort();
+        assert_eq!(
+            containing,
+            vec![
+                Interval::new(Included(6), Included(10)),
+                Interval::new(Excluded(8), Included(9))
+            ]
+        );
+
+        // (19,22) overlaps with [16,21) but is not contained in it
+        assert_eq!(
+            interval_tree.find_containing(&Interval::new(Excluded(19), Excluded(22))),
+            vec![Interval::new(Excluded(15), Excluded(23))]
+        );
+        assert_eq!(interval_tree.find_containing(&Interval::point(4)), vec![]);
+    }
+
+    #[test]
+    fn tree_interval_nearest_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+        assert!(interval_tree.nearest(&Interval::point(1)).is_none());
+
+        interval_tree.insert(Interval::new(Included(0), Included(2)));
+        interval_tree.insert(Interval::new(Included(1), Included(9)));
+        interval_tree.insert(Interval::new(Included(3), Included(4)));
+        interval_tree.insert(Interval::new(Included(20), Excluded(25)));
+        interval_tree.insert(Interval::new(Included(30), Included(31)));
+        interval_tree.insert(Interval::new(Excluded(32), Included(40)));
+
+        // query overlaps with an interval
+        let nearest = interval_tree.nearest(&Interval::new(Included(24), Included(26)));
+        assert!(nearest.unwrap() == Interval::new(Included(20), Excluded(25)));
+
+        // [1,9] reaches closer to the gap than [3,4], even though [3,4] starts later
+        let nearest = interval_tree.nearest(&Interval::new(Included(11), Included(12)));
+        assert!(nearest.unwrap() == Interval::new(Included(1), Included(9)));
+
+        // nearest neighbor above the query
+        let nearest = interval_tree.nearest(&Interval::new(Included(17), Included(18)));
+        assert!(nearest.unwrap() == Interval::new(Included(20), Excluded(25)));
+
+        // same distance on both sides favors the interval below
+        let nearest = interval_tree.nearest(&Interval::point(27));
+        assert!(nearest.unwrap() == Interval::new(Included(20), Excluded(25)));
+
+        // beyond all intervals
+        let nearest = interval_tree.nearest(&Interval::new(Excluded(50), Unbounded));
+        assert!(nearest.unwrap() == Interval::new(Excluded(32), Included(40)));
+
+        let nearest = interval_tree.nearest(&Interval::new(Excluded(31), Excluded(32)));
+        assert!(nearest.unwrap() == Interval::new(Included(30), Included(31)));
+    }
+
+    #[test]
+    fn tree_interval_nearest_2() {
+        let mut interval_tree = IntervalTree::<i64>::init();
+        for i in 0..100 {
+            interval_tree.insert(Interval::new(Included(i * 10), Included(i * 10 + (i % 7))));
+        }
+
+        for point in -10..1010 {
+            let query = Interval::point(point);
+            let nearest = interval_tree.nearest(&query).unwrap();
+
+            // brute force the smallest distance
+            let distance = |interval: &Interval<i64>| {
+                if Interval::overlaps(interval, &query) {
+                    0
+                } else if *interval < query {
+                    IntervalTree::_gap(interval, &query)
+                } else {
+                    IntervalTree::_gap(&query, interval)
+                }
+            };
+            let expected = interval_tree
+                .intervals()
+                .iter()
+                .map(distance)
+                .min()
+                .unwrap();
+
+            assert_eq!(distance(&nearest), expected);
+        }
+    }
+
+    #[test]
+    fn tree_interval_count_between_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+        for i in 0..100 {
+            interval_tree.insert(Interval::new(Included(i), Excluded(i + 10)));
+        }
+
+        let iv = |i: usize| Interval::new(Included(i), Excluded(i + 10));
+
+        // high boundary interval is in the tree
+        assert_eq!(interval_tree.count_between(&iv(10), &iv(20), false), 10);
+        assert_eq!(interval_tree.count_between(&iv(10), &iv(20), true), 11);
+
+        // high boundary interval is not in the tree
+        let high = Interval::new(Included(20), Excluded(25));
+        assert_eq!(interval_tree.count_between(&iv(10), &high, false), 10);
+        assert_eq!(interval_tree.count_between(&iv(10), &high, true), 10);
+
+        assert_eq!(interval_tree.count_between(&iv(0), &iv(99), true), 100);
+        assert_eq!(interval_tree.count_between(&iv(5), &iv(5), true), 1);
+        assert_eq!(interval_tree.count_between(&iv(5), &iv(5), false), 0);
+        assert_eq!(interval_tree.count_between(&iv(50), &iv(40), true), 0);
+
+        let empty = IntervalTree::<usize>::init();
+        assert_eq!(empty.count_between(&iv(0), &iv(10), true), 0);
+    }
+
+    #[test]
+    fn tree_interval_iter_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+        assert_eq!(interval_tree.iter().next(), None);
+
+        for i in 0..200 {
+            let low = (i * 37) % 101;
+            interval_tree.insert(Interval::new(Included(low), Excluded(low + i % 7 + 1)));
+        }
+
+        let intervals: Vec<&Interval<usize>> = interval_tree.iter().collect();
+        assert_eq!(intervals.len(), interval_tree.size());
+        assert!(intervals.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(intervals.into_iter().eq(interval_tree.intervals().iter()));
+    }
+
+    #[test]
+    fn tree_interval_max_overlap_count_1() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+        assert_eq!(interval_tree.max_overlap_count(), 0);
+
+        interval_tree.insert(Interval::new(Included(8), Included(9)));
+        assert_eq!(interval_tree.max_overlap_count(), 1);
+
+        interval_tree.insert(Interval::new(Included(1), Included(5)));
+        interval_tree.insert(Interval::new(Included(2), Included(6)));
+        interval_tree.insert(Interval::new(Included(4), Included(7)));
+        assert_eq!(interval_tree.max_overlap_count(), 3);
+
+        // intervals touching at a closed end overlap, but not at an open end
+        let mut interval_tree = IntervalTree::<usize>::init();
+        interval_tree.insert(Interval::new(Included(1), Excluded(3)));
+        interval_tree.insert(Interval::new(Included(3), Included(5)));
+        assert_eq!(interval_tree.max_overlap_count(), 1);
+
+        interval_tree.insert(Interval::new(Excluded(2), Included(3)));
+        assert_eq!(interval_tree.max_overlap_count(), 2);
+
+        interval_tree.insert(Interval::new(Unbounded, Excluded(1)));
+        interval_tree.insert(Interval::new(Excluded(0), Unbounded));
+        assert_eq!(interval_tree.max_overlap_count(), 3);
+    }
+
+    #[test]
+    fn tree_interval_max_overlap_count_2() {
+        let mut interval_tree = IntervalTree::<usize>::init();
+
+        let intervals: Vec<Interval<usize>> = (0..100)
+            .map(|i| {
+                let low = (i * 37) % 50;
+                let high = low + (i * 13) % 7;
+                match i % 3 {
+                    0 => Interval::new(Included(low), Included(high)),
+                    1 => Interval::new(Included(low), Excluded(high + 1)),
+                    _ => Interval::new(Excluded(low), Included(high + 1)),
+                }
+            })
+            .collect();
+        for interval in intervals.iter() {
+            interval_tree.insert(interval.duplicate());
+        }
+
+        // bounds are integers, so any point is either an integer or lies within an open unit interval
+        let queries = (0..60)
+            .map(Interval::point)
+            .chain((0..60).map(|i| Interval::new(Excluded(i), Excluded(i + 1))));
+        let brute_force = queries
+            .map(|query| {
+                intervals
+                    .iter()
+                    .filter(|interval| Interval::overlaps(interval, &query))
+                    .count()
+            })
+            .max()
+            .unwrap();
+
+        assert_eq!(interval_tree.max_overlap_count(), brute_force);
+    }
 }
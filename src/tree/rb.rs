@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
 
 const RED: bool = true;
 const BLACK: bool = false;
@@ -121,6 +122,94 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         RedBlack { root: None }
     }
 
+    /// Builds a Red Black tree out of `iter` in linear time without any rotations.
+    /// It is considerably faster than inserting entries one by one when entries are already sorted
+    ///
+    /// # Arguments
+    /// * `iter`: key-value pairs sorted in strictly ascending order of their keys
+    ///
+    /// # Panics
+    /// * panics in debug builds if keys of `iter` are not sorted in strictly ascending order
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let rb_tree = RedBlack::from_sorted_iter((0..10).map(|i| (i, i * 10)));
+    ///
+    /// assert_eq!(rb_tree.size(), 10);
+    /// assert_eq!(*rb_tree.get(&3).unwrap(), 30);
+    /// assert_eq!(rb_tree.keys(), vec![&0, &1, &2, &3, &4, &5, &6, &7, &8, &9]);
+    /// ```
+    pub fn from_sorted_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> RedBlack<K, V> {
+        let entries: Vec<(K, V)> = iter.into_iter().collect();
+
+        debug_assert!(
+            entries.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "Keys must be sorted in strictly ascending order"
+        );
+
+        // a 2-3 tree with h levels holds at most 3^h - 1 keys, pick the lowest h that can hold all entries
+        let mut capacity = 0;
+        while capacity < entries.len() {
+            capacity = capacity * 3 + 2;
+        }
+
+        let size = entries.len();
+        RedBlack {
+            root: RedBlack::_build_sorted(&mut entries.into_iter(), size, capacity),
+        }
+    }
+
+    // builds a black rooted subtree out of the next `size` entries of the sorted `entries` iterator.
+    // subtree corresponds to a 2-3 tree that can hold at most `capacity` keys, every path of it has the same number of black nodes
+    fn _build_sorted<I>(entries: &mut I, size: usize, capacity: usize) -> Option<Box<Node<K, V>>>
+    where
+        I: Iterator<Item = (K, V)>,
+    {
+        if size == 0 {
+            return None;
+        }
+
+        let child_capacity = (capacity - 2) / 3;
+
+        // a 2-node is used whenever its two children can hold the rest of the entries, otherwise a 3-node is used
+        if size - 1 <= 2 * child_capacity {
+            let left_size = (size - 1) / 2;
+            let left_child = RedBlack::_build_sorted(entries, left_size, child_capacity);
+
+            let (key, value) = entries.next().unwrap();
+            let mut node = Box::new(Node::init(key, value, BLACK, 1));
+
+            node.left_child = left_child;
+            node.right_child =
+                RedBlack::_build_sorted(entries, size - left_size - 1, child_capacity);
+            node.update_size();
+
+            return Some(node);
+        }
+
+        // a 3-node is a black node with a red left child
+        let first_size = (size - 2) / 3;
+        let second_size = (size - 2 - first_size) / 2;
+        let third_size = size - 2 - first_size - second_size;
+
+        let first_child = RedBlack::_build_sorted(entries, first_size, child_capacity);
+        let (key, value) = entries.next().unwrap();
+        let mut red = Box::new(Node::init(key, value, RED, 1));
+        red.left_child = first_child;
+        red.right_child = RedBlack::_build_sorted(entries, second_size, child_capacity);
+        red.update_size();
+
+        let (key, value) = entries.next().unwrap();
+        let mut node = Box::new(Node::init(key, value, BLACK, 1));
+        node.left_child = Some(red);
+        node.right_child = RedBlack::_build_sorted(entries, third_size, child_capacity);
+        node.update_size();
+
+        Some(node)
+    }
+
     /// Returns total number of nodes in the tree
     ///
     /// # Examples
@@ -192,6 +281,22 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         None
     }
 
+    fn _get_mut<'a>(node: &'a mut Option<Box<Node<K, V>>>, key: &K) -> Option<&'a mut V> {
+        if node.is_none() {
+            return None;
+        }
+
+        let node_ref = node.as_mut().unwrap();
+
+        if *key < *node_ref.key() {
+            RedBlack::_get_mut(&mut node_ref.left_child, key)
+        } else if *key > *node_ref.key() {
+            RedBlack::_get_mut(&mut node_ref.right_child, key)
+        } else {
+            node_ref.value.as_mut()
+        }
+    }
+
     /// Returns `true` if tree contains the specified `key`, false otherwise
     ///
     /// # Arguments
@@ -213,6 +318,122 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         !self.get(key).is_none()
     }
 
+    /// Returns `true` if tree contains the specified `key`, false otherwise.
+    /// It is an alias of `contains` matching the naming of maps in the standard library
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched in the tree
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// rb_tree.insert(1,10);
+    /// assert!(rb_tree.contains_key(&1));
+    /// assert!(!rb_tree.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.contains(key)
+    }
+
+    /// Returns whether `key` already existed in the tree together with a mutable reference to its value.
+    /// if `key` does not exist, it is inserted with `value` first. otherwise `value` is dropped and the existing value is kept.
+    /// The tree is walked twice: one descent finds or inserts `key` and rebalances on the way up,
+    /// then a second descent borrows the value by the rank of `key`, since rebalancing moves nodes after they are found
+    /// * Complexity: O(log(n)), two descents
+    ///
+    /// # Arguments
+    /// * `key`: key of the node to be returned or inserted
+    /// * `value`: value to be inserted if `key` does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// let (existed, value) = rb_tree.get_or_insert(1, 10);
+    /// assert!(!existed);
+    /// *value += 1;
+    ///
+    /// let (existed, value) = rb_tree.get_or_insert(1, 20);
+    /// assert!(existed);
+    /// assert_eq!(*value, 11);
+    /// ```
+    pub fn get_or_insert(&mut self, key: K, value: V) -> (bool, &mut V) {
+        let (root, existed, rank) = RedBlack::_get_or_insert(self.root.take(), key, value);
+
+        let mut root = root.unwrap();
+        root.color = BLACK;
+        self.root = Some(root);
+
+        (
+            existed,
+            RedBlack::_select_mut(&mut self.root, rank).unwrap(),
+        )
+    }
+
+    // returns the balanced subtree, whether `key` already existed and the rank of `key` in the subtree
+    fn _get_or_insert(
+        node: Option<Box<Node<K, V>>>,
+        key: K,
+        value: V,
+    ) -> (Option<Box<Node<K, V>>>, bool, usize) {
+        if node.is_none() {
+            return (Some(Box::new(Node::init(key, value, RED, 1))), false, 0);
+        }
+
+        let mut node_ref = node.unwrap();
+        let left_size = Node::size(&node_ref.left_child);
+
+        let (existed, rank) = if key < *node_ref.key() {
+            let (left_child, existed, rank) =
+                RedBlack::_get_or_insert(node_ref.left_child.take(), key, value);
+            node_ref.left_child = left_child;
+            (existed, rank)
+        } else if key > *node_ref.key() {
+            let (right_child, existed, rank) =
+                RedBlack::_get_or_insert(node_ref.right_child.take(), key, value);
+            node_ref.right_child = right_child;
+            (existed, left_size + 1 + rank)
+        } else {
+            (true, left_size)
+        };
+
+        // balance the tree
+        if Node::is_red(&node_ref.right_child) && !Node::is_red(&node_ref.left_child) {
+            node_ref = RedBlack::rotate_left(node_ref);
+        }
+        if Node::is_red(&node_ref.left_child) && Node::is_red(&node_ref.left_child().left_child) {
+            node_ref = RedBlack::rotate_right(node_ref);
+        }
+        if Node::is_red(&node_ref.left_child) && Node::is_red(&node_ref.right_child) {
+            RedBlack::flip_colors(&mut node_ref);
+        }
+
+        node_ref.update_size();
+
+        (Some(node_ref), existed, rank)
+    }
+
+    fn _select_mut(node: &mut Option<Box<Node<K, V>>>, k: usize) -> Option<&mut V> {
+        if node.is_none() {
+            return None;
+        }
+        let node_ref = node.as_mut().unwrap();
+
+        let left_size = Node::size(&node_ref.left_child);
+        if left_size > k {
+            RedBlack::_select_mut(&mut node_ref.left_child, k)
+        } else if left_size < k {
+            RedBlack::_select_mut(&mut node_ref.right_child, k - left_size - 1)
+        } else {
+            node_ref.value.as_mut()
+        }
+    }
+
     /// Insert a node which contains the specified `key` and `value` into the tree.
     /// if `key` already exists, this method will replace `value` as the new value of the node
     ///
@@ -274,6 +495,72 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         Some(node_ref)
     }
 
+    /// Applies `modify` to the value associated with `key` if it exists in the tree,
+    /// otherwise inserts `key` with `default` as its value. It only traverses the tree once
+    ///
+    /// # Arguments
+    /// * `key`: key of the node to be modified or inserted
+    /// * `default`: value to be inserted if `key` does not exist
+    /// * `modify`: closure applied to the existing value of `key`
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut word_count = RedBlack::<&str,usize>::init();
+    ///
+    /// for word in "a b a c a b".split(' ') {
+    ///     word_count.upsert(word, 1, |count| *count += 1);
+    /// }
+    ///
+    /// assert_eq!(*word_count.get(&"a").unwrap(), 3);
+    /// assert_eq!(*word_count.get(&"b").unwrap(), 2);
+    /// assert_eq!(*word_count.get(&"c").unwrap(), 1);
+    /// ```
+    pub fn upsert<F: FnOnce(&mut V)>(&mut self, key: K, default: V, modify: F) {
+        let mut root = RedBlack::_upsert(self.root.take(), key, default, modify).unwrap();
+
+        root.color = BLACK;
+
+        self.root = Some(root);
+    }
+
+    fn _upsert<F: FnOnce(&mut V)>(
+        node: Option<Box<Node<K, V>>>,
+        key: K,
+        default: V,
+        modify: F,
+    ) -> Option<Box<Node<K, V>>> {
+        if node.is_none() {
+            return Some(Box::new(Node::init(key, default, RED, 1)));
+        }
+
+        let mut node_ref = node.unwrap();
+
+        if key < *node_ref.key() {
+            node_ref.left_child = RedBlack::_upsert(node_ref.left_child, key, default, modify);
+        } else if key > *node_ref.key() {
+            node_ref.right_child = RedBlack::_upsert(node_ref.right_child, key, default, modify);
+        } else {
+            modify(node_ref.value.as_mut().unwrap());
+        }
+
+        // balance the tree
+        if Node::is_red(&node_ref.right_child) && !Node::is_red(&node_ref.left_child) {
+            node_ref = RedBlack::rotate_left(node_ref);
+        }
+        if Node::is_red(&node_ref.left_child) && Node::is_red(&node_ref.left_child().left_child) {
+            node_ref = RedBlack::rotate_right(node_ref);
+        }
+        if Node::is_red(&node_ref.left_child) && Node::is_red(&node_ref.right_child) {
+            RedBlack::flip_colors(&mut node_ref);
+        }
+
+        node_ref.update_size();
+
+        Some(node_ref)
+    }
+
     /// Deletes node with smallest key from the tree
     ///
     /// # Examples
@@ -295,8 +582,30 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
     /// assert_eq!(rb_tree.get(&2), None);
     /// ```
     pub fn delete_min(&mut self) {
+        self.pop_min();
+    }
+
+    /// Removes node with smallest key from the tree and returns its key and value.
+    /// Returns `None` if tree is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// rb_tree.insert(1,10);
+    /// rb_tree.insert(2,20);
+    /// rb_tree.insert(3,30);
+    /// rb_tree.insert(4,40);
+    ///
+    /// assert_eq!(rb_tree.pop_min(), Some((1, 10)));
+    /// assert_eq!(rb_tree.pop_min(), Some((2, 20)));
+    /// assert_eq!(rb_tree.get(&2), None);
+    /// ```
+    pub fn pop_min(&mut self) -> Option<(K, V)> {
         if self.root.is_none() {
-            return;
+            return None;
         }
 
         let mut root_ref = self.root.take().unwrap();
@@ -305,7 +614,8 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
             root_ref.color = RED;
         }
 
-        let mut root = RedBlack::_delete_min(Some(root_ref));
+        let mut removed = None;
+        let mut root = RedBlack::_delete_min(Some(root_ref), &mut removed);
 
         if !root.is_none() {
             root_ref = root.unwrap();
@@ -314,10 +624,18 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         }
 
         self.root = root;
+
+        let mut removed_ref = removed.unwrap();
+        Some((removed_ref.get_key(), removed_ref.get_value()))
     }
 
-    fn _delete_min(node: Option<Box<Node<K, V>>>) -> Option<Box<Node<K, V>>> {
+    // deletes the node with smallest key from the subtree and hands it back through `removed`
+    fn _delete_min(
+        node: Option<Box<Node<K, V>>>,
+        removed: &mut Option<Box<Node<K, V>>>,
+    ) -> Option<Box<Node<K, V>>> {
         if node.as_ref().unwrap().left_child.is_none() {
+            *removed = node;
             return None;
         }
 
@@ -327,7 +645,7 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
             node_ref = RedBlack::move_red_left(node_ref);
         }
 
-        node_ref.left_child = RedBlack::_delete_min(node_ref.left_child);
+        node_ref.left_child = RedBlack::_delete_min(node_ref.left_child, removed);
 
         Some(RedBlack::balance(node_ref))
     }
@@ -353,8 +671,30 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
     /// assert_eq!(rb_tree.get(&3), None);
     /// ```
     pub fn delete_max(&mut self) {
+        self.pop_max();
+    }
+
+    /// Removes node with largest key from the tree and returns its key and value.
+    /// Returns `None` if tree is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// rb_tree.insert(1,10);
+    /// rb_tree.insert(2,20);
+    /// rb_tree.insert(3,30);
+    /// rb_tree.insert(4,40);
+    ///
+    /// assert_eq!(rb_tree.pop_max(), Some((4, 40)));
+    /// assert_eq!(rb_tree.pop_max(), Some((3, 30)));
+    /// assert_eq!(rb_tree.get(&3), None);
+    /// ```
+    pub fn pop_max(&mut self) -> Option<(K, V)> {
         if self.root.is_none() {
-            return;
+            return None;
         }
 
         let mut root_ref = self.root.take().unwrap();
@@ -363,7 +703,8 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
             root_ref.color = RED;
         }
 
-        let mut root = RedBlack::_delete_max(Some(root_ref));
+        let mut removed = None;
+        let mut root = RedBlack::_delete_max(Some(root_ref), &mut removed);
 
         if !root.is_none() {
             root_ref = root.unwrap();
@@ -372,9 +713,16 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         }
 
         self.root = root;
+
+        let mut removed_ref = removed.unwrap();
+        Some((removed_ref.get_key(), removed_ref.get_value()))
     }
 
-    fn _delete_max(node: Option<Box<Node<K, V>>>) -> Option<Box<Node<K, V>>> {
+    // deletes the node with largest key from the subtree and hands it back through `removed`
+    fn _delete_max(
+        node: Option<Box<Node<K, V>>>,
+        removed: &mut Option<Box<Node<K, V>>>,
+    ) -> Option<Box<Node<K, V>>> {
         if node.is_none() {
             return None;
         }
@@ -386,6 +734,7 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         }
 
         if node_ref.right_child.is_none() {
+            *removed = Some(node_ref);
             return None;
         }
 
@@ -394,7 +743,7 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
             node_ref = RedBlack::move_red_right(node_ref);
         }
 
-        node_ref.right_child = RedBlack::_delete_max(node_ref.right_child);
+        node_ref.right_child = RedBlack::_delete_max(node_ref.right_child, removed);
 
         Some(RedBlack::balance(node_ref))
     }
@@ -474,7 +823,7 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
                 // swap values
                 std::mem::swap(x.value_mut(), node_ref.value_mut());
 
-                node_ref.right_child = RedBlack::_delete_min(node_ref.right_child);
+                node_ref.right_child = RedBlack::_delete_min(node_ref.right_child, &mut None);
             } else {
                 node_ref.right_child = RedBlack::_delete(node_ref.right_child, key);
             }
@@ -496,6 +845,55 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         }
     }
 
+    /// Retains only the entries specified by the predicate `f` and removes the rest
+    /// * Complexity: O(n log(n))
+    ///
+    /// # Arguments
+    /// * `f`: predicate that returns true for the entries that must be kept
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// rb_tree.insert(1,10);
+    /// rb_tree.insert(2,20);
+    /// rb_tree.insert(3,30);
+    /// rb_tree.insert(4,40);
+    ///
+    /// rb_tree.retain(|key, _| key % 2 == 0);
+    ///
+    /// assert_eq!(rb_tree.size(), 2);
+    /// assert_eq!(rb_tree.keys(), vec![&2, &4]);
+    /// ```
+    pub fn retain<F: FnMut(&K, &V) -> bool>(&mut self, mut f: F) {
+        let mut entries = Vec::with_capacity(self.size());
+        RedBlack::_into_entries_in_order(self.root.take(), &mut entries);
+
+        for (key, value) in entries {
+            if f(&key, &value) {
+                self.insert(key, value);
+            }
+        }
+    }
+
+    fn _into_entries_in_order(node: Option<Box<Node<K, V>>>, entries: &mut Vec<(K, V)>) {
+        if node.is_none() {
+            return;
+        }
+
+        let mut node_ref = node.unwrap();
+
+        RedBlack::_into_entries_in_order(node_ref.left_child.take(), entries);
+
+        let key = node_ref.get_key();
+        let value = node_ref.get_value();
+        entries.push((key, value));
+
+        RedBlack::_into_entries_in_order(node_ref.right_child.take(), entries);
+    }
+
     /// Returns the height of the tree.
     /// An empty tree has height -1 and a tree with one node has height 0
     pub fn height(&self) -> i64 {
@@ -515,53 +913,44 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         );
     }
 
-    /// Returns the largest key in the tree less than or equal to `key`
+    /// Returns the average number of edges from the root to the nodes of the tree.
+    /// An empty tree has average depth 0
     ///
-    /// # Arguments
-    /// * `key`: key to be searched for
-    ///
-    /// # Examples:
+    /// # Examples
     /// ```
     /// use rudac::tree::RedBlack;
     ///
     /// let mut rb_tree = RedBlack::<usize,usize>::init();
     ///
     /// rb_tree.insert(1,10);
-    /// rb_tree.insert(3,20);
-    /// rb_tree.insert(5,30);
-    /// rb_tree.insert(7,40);
+    /// rb_tree.insert(2,20);
+    /// rb_tree.insert(3,30);
     ///
-    /// assert_eq!(*rb_tree.floor(&2).unwrap(), 1);
-    /// assert_eq!(rb_tree.floor(&0), None);
+    /// // 2 is the root and 1 and 3 are its children: (0 + 1 + 1) / 3
+    /// assert!((rb_tree.average_depth() - 2.0 / 3.0).abs() < 1e-9);
     /// ```
-    pub fn floor(&self, key: &K) -> Option<&K> {
-        RedBlack::_floor(&self.root, key)
+    pub fn average_depth(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        RedBlack::_total_depth(&self.root, 0) as f64 / self.size() as f64
     }
 
-    fn _floor<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<&'a K> {
+    // sum of depths of all nodes in the subtree rooted at `node`, which itself is at `depth`
+    fn _total_depth(node: &Option<Box<Node<K, V>>>, depth: usize) -> usize {
         if node.is_none() {
-            return None;
+            return 0;
         }
 
         let node_ref = node.as_ref().unwrap();
 
-        if *key == *node_ref.key() {
-            return Some(node_ref.key());
-        }
-        if *key < *node_ref.key() {
-            return RedBlack::_floor(&node_ref.left_child, key);
-        }
-
-        let found_key = RedBlack::_floor(&node_ref.right_child, key);
-
-        if found_key.is_some() {
-            return found_key;
-        } else {
-            return Some(node_ref.key());
-        }
+        depth
+            + RedBlack::_total_depth(&node_ref.left_child, depth + 1)
+            + RedBlack::_total_depth(&node_ref.right_child, depth + 1)
     }
 
-    /// Returns the smallest key in the tree greater than or equal to `key`
+    /// Returns the largest key in the tree less than or equal to `key`
     ///
     /// # Arguments
     /// * `key`: key to be searched for
@@ -577,14 +966,104 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
     /// rb_tree.insert(5,30);
     /// rb_tree.insert(7,40);
     ///
-    /// assert_eq!(*rb_tree.ceiling(&6).unwrap(), 7);
-    /// assert_eq!(rb_tree.ceiling(&8), None);
+    /// assert_eq!(*rb_tree.floor(&2).unwrap(), 1);
+    /// assert_eq!(rb_tree.floor(&0), None);
+    /// ```
+    pub fn floor(&self, key: &K) -> Option<&K> {
+        RedBlack::_floor(&self.root, key).map(|(key, _)| key)
+    }
+
+    /// Returns the largest key in the tree less than or equal to `key` together with its associated value
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched for
+    ///
+    /// # Examples:
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// rb_tree.insert(1,10);
+    /// rb_tree.insert(3,20);
+    /// rb_tree.insert(5,30);
+    /// rb_tree.insert(7,40);
+    ///
+    /// assert_eq!(rb_tree.floor_entry(&4), Some((&3, &20)));
+    /// ```
+    pub fn floor_entry(&self, key: &K) -> Option<(&K, &V)> {
+        RedBlack::_floor(&self.root, key)
+    }
+
+    fn _floor<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<(&'a K, &'a V)> {
+        if node.is_none() {
+            return None;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+
+        if *key == *node_ref.key() {
+            return Some((node_ref.key(), node_ref.value()));
+        }
+        if *key < *node_ref.key() {
+            return RedBlack::_floor(&node_ref.left_child, key);
+        }
+
+        let found_entry = RedBlack::_floor(&node_ref.right_child, key);
+
+        if found_entry.is_some() {
+            found_entry
+        } else {
+            Some((node_ref.key(), node_ref.value()))
+        }
+    }
+
+    /// Returns the smallest key in the tree greater than or equal to `key`
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched for
+    ///
+    /// # Examples:
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// rb_tree.insert(1,10);
+    /// rb_tree.insert(3,20);
+    /// rb_tree.insert(5,30);
+    /// rb_tree.insert(7,40);
+    ///
+    /// assert_eq!(*rb_tree.ceiling(&6).unwrap(), 7);
+    /// assert_eq!(rb_tree.ceiling(&8), None);
     /// ```
     pub fn ceiling(&self, key: &K) -> Option<&K> {
+        RedBlack::_ceiling(&self.root, key).map(|(key, _)| key)
+    }
+
+    /// Returns the smallest key in the tree greater than or equal to `key` together with its associated value
+    ///
+    /// # Arguments
+    /// * `key`: key to be searched for
+    ///
+    /// # Examples:
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// rb_tree.insert(1,10);
+    /// rb_tree.insert(3,20);
+    /// rb_tree.insert(5,30);
+    /// rb_tree.insert(7,40);
+    ///
+    /// assert_eq!(rb_tree.ceiling_entry(&4), Some((&5, &30)));
+    /// ```
+    pub fn ceiling_entry(&self, key: &K) -> Option<(&K, &V)> {
         RedBlack::_ceiling(&self.root, key)
     }
 
-    fn _ceiling<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<&'a K> {
+    fn _ceiling<'a>(node: &'a Option<Box<Node<K, V>>>, key: &K) -> Option<(&'a K, &'a V)> {
         if node.is_none() {
             return None;
         }
@@ -592,18 +1071,18 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         let node_ref = node.as_ref().unwrap();
 
         if *key == *node_ref.key() {
-            return Some(node_ref.key());
+            return Some((node_ref.key(), node_ref.value()));
         }
         if *key > *node_ref.key() {
             return RedBlack::_ceiling(&node_ref.right_child, key);
         }
 
-        let found_key = RedBlack::_ceiling(&node_ref.left_child, key);
+        let found_entry = RedBlack::_ceiling(&node_ref.left_child, key);
 
-        if found_key.is_some() {
-            return found_key;
+        if found_entry.is_some() {
+            found_entry
         } else {
-            return Some(node_ref.key());
+            Some((node_ref.key(), node_ref.value()))
         }
     }
 
@@ -655,6 +1134,67 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         }
     }
 
+    /// Returns keys and their associated values with ranks in range [`start`, `end`) in ascending order of their keys.
+    /// `end` is capped at the size of the tree
+    ///
+    /// # Arguments
+    /// * `start`: rank of the first entry(inclusive)
+    /// * `end`: rank of the last entry(exclusive)
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize,usize>::init();
+    ///
+    /// rb_tree.insert(1,10);
+    /// rb_tree.insert(3,20);
+    /// rb_tree.insert(5,30);
+    /// rb_tree.insert(7,40);
+    ///
+    /// assert_eq!(rb_tree.select_range(1, 3), vec![(&3, &20), (&5, &30)]);
+    /// assert_eq!(rb_tree.select_range(3, 10), vec![(&7, &40)]);
+    /// ```
+    pub fn select_range(&self, start: usize, end: usize) -> Vec<(&K, &V)> {
+        let end = std::cmp::min(end, self.size());
+
+        let mut entries = Vec::new();
+        if start < end {
+            entries.reserve(end - start);
+            RedBlack::_select_range(&self.root, start, end, 0, &mut entries);
+        }
+
+        entries
+    }
+
+    // `offset` is the number of keys smaller than all keys in the subtree rooted at `node`
+    fn _select_range<'a>(
+        node: &'a Option<Box<Node<K, V>>>,
+        start: usize,
+        end: usize,
+        offset: usize,
+        entries: &mut Vec<(&'a K, &'a V)>,
+    ) {
+        if node.is_none() {
+            return;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+
+        let rank = offset + Node::size(&node_ref.left_child);
+
+        // only visit subtrees that contain ranks in range
+        if start < rank {
+            RedBlack::_select_range(&node_ref.left_child, start, end, offset, entries);
+        }
+        if start <= rank && rank < end {
+            entries.push((node_ref.key(), node_ref.value()));
+        }
+        if rank + 1 < end {
+            RedBlack::_select_range(&node_ref.right_child, start, end, rank + 1, entries);
+        }
+    }
+
     /// Returns the smallest key and its associated value in the tree
     ///
     /// # Examples
@@ -787,6 +1327,149 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         RedBlack::_keys_in_order(&node_ref.right_child, keys);
     }
 
+    // pushes references to entries of the subtree rooted at `node` into `entries` following an in-order traversal
+    fn _entries_in_order<'a>(node: &'a Option<Box<Node<K, V>>>, entries: &mut Vec<(&'a K, &'a V)>) {
+        if node.is_none() {
+            return;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        RedBlack::_entries_in_order(&node_ref.left_child, entries);
+        entries.push((node_ref.key(), node_ref.value()));
+        RedBlack::_entries_in_order(&node_ref.right_child, entries);
+    }
+
+    fn entries_in_order(&self) -> Vec<(&K, &V)> {
+        let mut entries = Vec::with_capacity(self.size());
+
+        RedBlack::_entries_in_order(&self.root, &mut entries);
+
+        entries
+    }
+
+    /// Returns all values in the tree following an in-order traversal.
+    /// Therefore values are sorted based on their associated keys from smallest to largest
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize, usize>::init();
+    ///
+    /// for i in (1..100).rev() {
+    ///     rb_tree.insert(i, i * 10);
+    /// }
+    ///
+    /// let mut i = 1;
+    /// // values are sorted by their keys: [10, 20, 30,..., 990]
+    /// for value in rb_tree.values() {
+    ///     assert!(*value == i * 10);
+    ///     i += 1;
+    /// }
+    /// ```
+    pub fn values(&self) -> Vec<&V> {
+        let mut values: Vec<&V> = Vec::new();
+
+        RedBlack::_values_in_order(&self.root, &mut values);
+
+        values
+    }
+
+    fn _values_in_order<'a>(node: &'a Option<Box<Node<K, V>>>, values: &mut Vec<&'a V>) {
+        if node.is_none() {
+            return;
+        }
+
+        let node_ref = node.as_ref().unwrap();
+        RedBlack::_values_in_order(&node_ref.left_child, values);
+        values.push(node_ref.value());
+        RedBlack::_values_in_order(&node_ref.right_child, values);
+    }
+
+    /// Returns a lazy iterator over the values in the tree following an in-order traversal.
+    /// Therefore values are sorted based on their associated keys from smallest to largest.
+    /// Unlike collecting all values into a vector, it only keeps the path to the next node: O(height) space
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize, usize>::init();
+    ///
+    /// for i in (1..100).rev() {
+    ///     rb_tree.insert(i, i * 10);
+    /// }
+    ///
+    /// // values are sorted by their keys: [10, 20, 30,..., 990]
+    /// assert!(rb_tree.values_iter().eq((1..100).map(|i| i * 10).collect::<Vec<_>>().iter()));
+    /// ```
+    pub fn values_iter(&self) -> impl Iterator<Item = &V> {
+        // nodes whose left subtree is visited but they themselves are not
+        let mut stack: Vec<&Node<K, V>> = Vec::new();
+        let mut current = self.root.as_deref();
+
+        std::iter::from_fn(move || {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left_child.as_deref();
+            }
+
+            let node = stack.pop()?;
+            current = node.right_child.as_deref();
+
+            Some(node.value())
+        })
+    }
+
+    /// Returns mutable references to all values in the tree following an in-order traversal.
+    /// Therefore values are sorted based on their associated keys from smallest to largest
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut rb_tree = RedBlack::<usize, usize>::init();
+    ///
+    /// for i in 1..100 {
+    ///     rb_tree.insert(i, i);
+    /// }
+    ///
+    /// for value in rb_tree.values_mut() {
+    ///     *value *= 2;
+    /// }
+    ///
+    /// assert_eq!(*rb_tree.get(&10).unwrap(), 20);
+    /// ```
+    pub fn values_mut(&mut self) -> Vec<&mut V> {
+        let mut values: Vec<&mut V> = Vec::new();
+
+        RedBlack::_values_mut_in_order(&mut self.root, &mut values);
+
+        values
+    }
+
+    fn _values_mut_in_order<'a>(
+        node: &'a mut Option<Box<Node<K, V>>>,
+        values: &mut Vec<&'a mut V>,
+    ) {
+        if node.is_none() {
+            return;
+        }
+
+        // borrow children and value of the node separately so they can be handed out independently
+        let node_ref = node.as_mut().unwrap();
+        let Node {
+            left_child,
+            value,
+            right_child,
+            ..
+        } = &mut **node_ref;
+
+        RedBlack::_values_mut_in_order(left_child, values);
+        values.push(value.as_mut().unwrap());
+        RedBlack::_values_mut_in_order(right_child, values);
+    }
+
     /// Returns all keys in the tree following a level-order traversal
     pub fn keys_in_level_order(&self) -> Vec<&K> {
         let mut keys: Vec<&K> = Vec::new();
@@ -898,6 +1581,148 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
         return self.rank(high_key) - self.rank(low_key);
     }
 
+    /// Joins two trees into one tree. all keys of `left` must be less than all keys of `right`.
+    /// Smaller tree is attached to the larger one at the node with the same black height. Complexity: O(log n)
+    ///
+    /// # Arguments
+    /// * `left`: tree with smaller keys
+    /// * `right`: tree with larger keys
+    ///
+    /// # Panics
+    /// * panics if largest key of `left` is not less than smallest key of `right`
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::tree::RedBlack;
+    ///
+    /// let mut left = RedBlack::<usize, usize>::init();
+    /// let mut right = RedBlack::<usize, usize>::init();
+    ///
+    /// for i in 0..5 {
+    ///     left.insert(i, i * 10);
+    ///     right.insert(i + 5, (i + 5) * 10);
+    /// }
+    ///
+    /// let rb_tree = RedBlack::join(left, right);
+    ///
+    /// assert_eq!(rb_tree.size(), 10);
+    /// assert_eq!(rb_tree.keys(), vec![&0, &1, &2, &3, &4, &5, &6, &7, &8, &9]);
+    /// ```
+    pub fn join(mut left: RedBlack<K, V>, mut right: RedBlack<K, V>) -> RedBlack<K, V> {
+        if !left.is_empty()
+            && !right.is_empty()
+            && *left.max().unwrap().0 >= *right.min().unwrap().0
+        {
+            panic!("Keys of left tree must be less than keys of right tree");
+        }
+
+        // smallest entry of `right` becomes the red node that links the two trees
+        let pivot = match right.pop_min() {
+            Some((key, value)) => Box::new(Node::init(key, value, RED, 1)),
+            None => return left,
+        };
+
+        let left_height = RedBlack::black_height(&left.root);
+        let right_height = RedBlack::black_height(&right.root);
+
+        let mut root = if left_height >= right_height {
+            RedBlack::_join_right(
+                left.root.take(),
+                left_height,
+                pivot,
+                right.root.take(),
+                right_height,
+            )
+        } else {
+            RedBlack::_join_left(
+                left.root.take(),
+                left_height,
+                pivot,
+                right.root.take(),
+                right_height,
+            )
+        };
+        root.color = BLACK;
+
+        RedBlack { root: Some(root) }
+    }
+
+    // descends the right spine of the left tree until it reaches a subtree with black height of the right tree
+    fn _join_right(
+        node: Option<Box<Node<K, V>>>,
+        height: usize,
+        mut pivot: Box<Node<K, V>>,
+        right: Option<Box<Node<K, V>>>,
+        right_height: usize,
+    ) -> Box<Node<K, V>> {
+        if height == right_height {
+            pivot.left_child = node;
+            pivot.right_child = right;
+            pivot.update_size();
+
+            return pivot;
+        }
+
+        // right links are black, so black height drops by one at each step
+        let mut node_ref = node.unwrap();
+        node_ref.right_child = Some(RedBlack::_join_right(
+            node_ref.right_child.take(),
+            height - 1,
+            pivot,
+            right,
+            right_height,
+        ));
+
+        RedBlack::balance(node_ref)
+    }
+
+    // descends the left spine of the right tree until it reaches a black subtree with black height of the left tree
+    fn _join_left(
+        left: Option<Box<Node<K, V>>>,
+        left_height: usize,
+        mut pivot: Box<Node<K, V>>,
+        node: Option<Box<Node<K, V>>>,
+        height: usize,
+    ) -> Box<Node<K, V>> {
+        if height == left_height && !Node::is_red(&node) {
+            pivot.left_child = left;
+            pivot.right_child = node;
+            pivot.update_size();
+
+            return pivot;
+        }
+
+        let mut node_ref = node.unwrap();
+        let child_height = if node_ref.color == BLACK {
+            height - 1
+        } else {
+            height
+        };
+        node_ref.left_child = Some(RedBlack::_join_left(
+            left,
+            left_height,
+            pivot,
+            node_ref.left_child.take(),
+            child_height,
+        ));
+
+        RedBlack::balance(node_ref)
+    }
+
+    // number of black nodes on the path from `node` to its leftmost leaf
+    fn black_height(mut node: &Option<Box<Node<K, V>>>) -> usize {
+        let mut height = 0;
+
+        while let Some(node_ref) = node {
+            if node_ref.color == BLACK {
+                height += 1;
+            }
+            node = &node_ref.left_child;
+        }
+
+        height
+    }
+
     fn rotate_left(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
         let mut y = node.right_child.unwrap();
         node.right_child = y.left_child;
@@ -994,24 +1819,101 @@ impl<K: std::cmp::Ord, V> RedBlack<K, V> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Returns a reference to the value associated with `key`
+///
+/// # Panics
+/// * panics if `key` does not exist in the tree
+///
+/// # Examples
+/// ```
+/// use rudac::tree::RedBlack;
+///
+/// let mut rb_tree = RedBlack::<usize, usize>::init();
+///
+/// rb_tree.insert(1, 10);
+/// assert_eq!(rb_tree[&1], 10);
+///
+/// rb_tree[&1] = 20;
+/// assert_eq!(rb_tree[&1], 20);
+/// ```
+impl<K: std::cmp::Ord, V> std::ops::Index<&K> for RedBlack<K, V> {
+    type Output = V;
 
-    fn is_23<K: std::cmp::Ord, V>(node: &Option<Box<Node<K, V>>>, is_root: bool) -> bool {
-        if node.is_none() {
-            return true;
+    fn index(&self, key: &K) -> &Self::Output {
+        match RedBlack::_get(&self.root, key) {
+            Some(value) => value,
+            None => panic!("Key does not exist in the tree"),
         }
-        let node_ref = node.as_ref().unwrap();
+    }
+}
 
-        if Node::is_red(&node_ref.right_child) {
-            return false;
-        }
-        if !is_root && Node::is_red(node) && Node::is_red(&node_ref.left_child) {
-            return false;
+/// Returns a mutable reference to the value associated with `key`
+///
+/// # Panics
+/// * panics if `key` does not exist in the tree
+impl<K: std::cmp::Ord, V> std::ops::IndexMut<&K> for RedBlack<K, V> {
+    fn index_mut(&mut self, key: &K) -> &mut Self::Output {
+        match RedBlack::_get_mut(&mut self.root, key) {
+            Some(value) => value,
+            None => panic!("Key does not exist in the tree"),
         }
-
-        return is_23(&node_ref.left_child, false) && is_23(&node_ref.right_child, false);
+    }
+}
+
+/// Two trees are equal if they contain the same entries, regardless of their shape
+impl<K: std::cmp::Ord, V: PartialEq> PartialEq for RedBlack<K, V> {
+    fn eq(&self, other: &RedBlack<K, V>) -> bool {
+        self.size() == other.size() && self.entries_in_order() == other.entries_in_order()
+    }
+}
+
+impl<K: std::cmp::Ord, V: Eq> Eq for RedBlack<K, V> {}
+
+/// Hashes the entries in ascending order of their keys,
+/// so trees with the same entries hash equally regardless of their shape
+///
+/// # Examples
+/// ```
+/// use rudac::tree::RedBlack;
+/// use std::collections::HashSet;
+///
+/// let mut rb_tree1 = RedBlack::<usize, usize>::init();
+/// rb_tree1.insert(1, 10);
+/// rb_tree1.insert(2, 20);
+///
+/// let mut rb_tree2 = RedBlack::<usize, usize>::init();
+/// rb_tree2.insert(2, 20);
+/// rb_tree2.insert(1, 10);
+///
+/// let mut set = HashSet::new();
+/// set.insert(rb_tree1);
+///
+/// assert!(set.contains(&rb_tree2));
+/// ```
+impl<K: std::cmp::Ord + Hash, V: Hash> Hash for RedBlack<K, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entries_in_order().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_23<K: std::cmp::Ord, V>(node: &Option<Box<Node<K, V>>>, is_root: bool) -> bool {
+        if node.is_none() {
+            return true;
+        }
+        let node_ref = node.as_ref().unwrap();
+
+        if Node::is_red(&node_ref.right_child) {
+            return false;
+        }
+        if !is_root && Node::is_red(node) && Node::is_red(&node_ref.left_child) {
+            return false;
+        }
+
+        return is_23(&node_ref.left_child, false) && is_23(&node_ref.right_child, false);
     }
 
     fn is_bst<K: std::cmp::Ord, V>(
@@ -1051,6 +1953,26 @@ mod tests {
             && is_size_consistent(&node_ref.right_child);
     }
 
+    // returns black height of the subtree if all paths to its leaves have the same number of black nodes
+    fn black_height<K: std::cmp::Ord, V>(node: &Option<Box<Node<K, V>>>) -> Option<usize> {
+        if node.is_none() {
+            return Some(0);
+        }
+        let node_ref = node.as_ref().unwrap();
+
+        let left_height = black_height(&node_ref.left_child)?;
+        let right_height = black_height(&node_ref.right_child)?;
+        if left_height != right_height {
+            return None;
+        }
+
+        if Node::is_red(node) {
+            Some(left_height)
+        } else {
+            Some(left_height + 1)
+        }
+    }
+
     fn is_rank_consistent<K: std::cmp::Ord, V>(rb_tree: &RedBlack<K, V>) -> bool {
         for i in 0..Node::size(&rb_tree.root) {
             if i != rb_tree.rank(rb_tree.select(i).unwrap().0) {
@@ -1318,6 +2240,445 @@ mod tests {
         assert!(is_rank_consistent(&rb_tree));
     }
 
+    #[test]
+    fn tree_rb_upsert_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+
+        // every key is inserted on its first occurrence and modified on the following ones
+        for i in 0..300 {
+            rb_tree.upsert(i % 100, i, |value| *value += i);
+        }
+
+        assert_eq!(rb_tree.size(), 100);
+        for i in 0..100 {
+            assert_eq!(*rb_tree.get(&i).unwrap(), i + (i + 100) + (i + 200));
+        }
+
+        assert!(is_23(&rb_tree.root, true));
+        assert!(is_bst(&rb_tree.root, None, None));
+        assert!(is_size_consistent(&rb_tree.root));
+        assert!(is_rank_consistent(&rb_tree));
+    }
+
+    #[test]
+    fn tree_rb_upsert_2() {
+        let mut rb_tree = RedBlack::<usize, String>::init();
+        let mut modify_calls = 0;
+
+        rb_tree.upsert(1, String::from("a"), |_| modify_calls += 1);
+        assert_eq!(modify_calls, 0);
+        assert_eq!(*rb_tree.get(&1).unwrap(), String::from("a"));
+
+        rb_tree.upsert(1, String::from("b"), |value| {
+            modify_calls += 1;
+            value.push('c');
+        });
+        assert_eq!(modify_calls, 1);
+        assert_eq!(*rb_tree.get(&1).unwrap(), String::from("ac"));
+    }
+
+    #[test]
+    fn tree_rb_select_range_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+
+        for i in (0..1000).rev() {
+            rb_tree.insert(i, i * 10);
+        }
+
+        let entries = rb_tree.select_range(100, 110);
+        assert_eq!(entries.len(), 10);
+        for (index, (key, value)) in entries.into_iter().enumerate() {
+            assert_eq!(*key, 100 + index);
+            assert_eq!(*value, (100 + index) * 10);
+        }
+
+        assert_eq!(rb_tree.select_range(0, 1000).len(), 1000);
+        assert_eq!(rb_tree.select_range(995, 2000).len(), 5);
+        assert_eq!(rb_tree.select_range(10, 10), vec![]);
+        assert_eq!(rb_tree.select_range(20, 10), vec![]);
+        assert_eq!(rb_tree.select_range(1000, 1010), vec![]);
+    }
+
+    #[test]
+    fn tree_rb_index_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+
+        for i in 0..100 {
+            rb_tree.insert(i, i * 10);
+        }
+
+        for i in 0..100 {
+            assert_eq!(rb_tree[&i], i * 10);
+        }
+
+        for i in 0..100 {
+            rb_tree[&i] += 1;
+        }
+
+        for i in 0..100 {
+            assert_eq!(rb_tree[&i], i * 10 + 1);
+            assert_eq!(*rb_tree.get(&i).unwrap(), i * 10 + 1);
+        }
+
+        assert!(is_23(&rb_tree.root, true));
+        assert!(is_bst(&rb_tree.root, None, None));
+        assert!(is_size_consistent(&rb_tree.root));
+        assert!(is_rank_consistent(&rb_tree));
+    }
+
+    #[test]
+    #[should_panic(expected = "Key does not exist in the tree")]
+    fn tree_rb_panic_index() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+        rb_tree.insert(1, 10);
+
+        let _ = rb_tree[&2];
+    }
+
+    #[test]
+    #[should_panic(expected = "Key does not exist in the tree")]
+    fn tree_rb_panic_index_mut() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+        rb_tree.insert(1, 10);
+
+        rb_tree[&2] = 20;
+    }
+
+    #[test]
+    fn tree_rb_join_1() {
+        let mut left = RedBlack::<usize, usize>::init();
+        let mut right = RedBlack::<usize, usize>::init();
+
+        for i in 0..50 {
+            left.insert(i, i * 10);
+        }
+        for i in 50..100 {
+            right.insert(i, i * 10);
+        }
+
+        let rb_tree = RedBlack::join(left, right);
+
+        assert_eq!(rb_tree.size(), 100);
+        for (i, key) in rb_tree.keys().into_iter().enumerate() {
+            assert_eq!(*key, i);
+            assert_eq!(*rb_tree.get(key).unwrap(), i * 10);
+        }
+
+        assert!(!Node::is_red(&rb_tree.root));
+        assert!(black_height(&rb_tree.root).is_some());
+        assert!(is_23(&rb_tree.root, true));
+        assert!(is_bst(&rb_tree.root, None, None));
+        assert!(is_size_consistent(&rb_tree.root));
+        assert!(is_rank_consistent(&rb_tree));
+    }
+
+    #[test]
+    fn tree_rb_join_2() {
+        // join trees of very different black heights in both directions
+        let sizes = [0, 1, 3, 37, 500];
+
+        for left_size in sizes.iter().copied() {
+            for right_size in sizes.iter().copied() {
+                let mut left = RedBlack::<usize, usize>::init();
+                let mut right = RedBlack::<usize, usize>::init();
+
+                for i in 0..left_size {
+                    left.insert(i, i);
+                }
+                for i in left_size..left_size + right_size {
+                    right.insert(i, i);
+                }
+
+                let mut rb_tree = RedBlack::join(left, right);
+
+                assert_eq!(rb_tree.size(), left_size + right_size);
+                for (i, key) in rb_tree.keys().into_iter().enumerate() {
+                    assert_eq!(*key, i);
+                }
+                assert!(!Node::is_red(&rb_tree.root));
+                assert!(black_height(&rb_tree.root).is_some());
+                assert!(is_23(&rb_tree.root, true));
+                assert!(is_bst(&rb_tree.root, None, None));
+                assert!(is_size_consistent(&rb_tree.root));
+                assert!(is_rank_consistent(&rb_tree));
+
+                // joined tree must stay valid under further updates
+                rb_tree.insert(left_size + right_size, 0);
+                rb_tree.delete_min();
+                assert!(is_23(&rb_tree.root, true));
+                assert!(black_height(&rb_tree.root).is_some());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys of left tree must be less than keys of right tree")]
+    fn tree_rb_panic_join() {
+        let mut left = RedBlack::<usize, usize>::init();
+        let mut right = RedBlack::<usize, usize>::init();
+
+        left.insert(5, 5);
+        right.insert(5, 5);
+
+        RedBlack::join(left, right);
+    }
+
+    #[test]
+    fn tree_rb_retain_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+        for i in 0..100 {
+            rb_tree.insert(i, i * 10);
+        }
+
+        rb_tree.retain(|key, _| key % 2 == 1);
+
+        assert_eq!(rb_tree.size(), 50);
+        for key in rb_tree.keys() {
+            assert_eq!(key % 2, 1);
+            assert_eq!(*rb_tree.get(key).unwrap(), key * 10);
+        }
+
+        assert!(is_23(&rb_tree.root, true));
+        assert!(black_height(&rb_tree.root).is_some());
+        assert!(is_bst(&rb_tree.root, None, None));
+        assert!(is_size_consistent(&rb_tree.root));
+        assert!(is_rank_consistent(&rb_tree));
+
+        rb_tree.retain(|_, value| *value > 1000);
+        assert!(rb_tree.is_empty());
+    }
+
+    #[test]
+    fn tree_rb_floor_ceiling_entry_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+        for i in (10..100).step_by(10) {
+            rb_tree.insert(i, i * 2);
+        }
+
+        assert_eq!(rb_tree.floor_entry(&5), None);
+        assert_eq!(rb_tree.floor_entry(&10), Some((&10, &20)));
+        assert_eq!(rb_tree.floor_entry(&25), Some((&20, &40)));
+        assert_eq!(rb_tree.floor_entry(&89), Some((&80, &160)));
+        assert_eq!(rb_tree.floor_entry(&1000), Some((&90, &180)));
+
+        assert_eq!(rb_tree.ceiling_entry(&5), Some((&10, &20)));
+        assert_eq!(rb_tree.ceiling_entry(&10), Some((&10, &20)));
+        assert_eq!(rb_tree.ceiling_entry(&25), Some((&30, &60)));
+        assert_eq!(rb_tree.ceiling_entry(&89), Some((&90, &180)));
+        assert_eq!(rb_tree.ceiling_entry(&91), None);
+
+        for probe in 0..100 {
+            assert_eq!(
+                rb_tree.floor_entry(&probe).map(|(k, _)| k),
+                rb_tree.floor(&probe)
+            );
+            assert_eq!(
+                rb_tree.ceiling_entry(&probe).map(|(k, _)| k),
+                rb_tree.ceiling(&probe)
+            );
+        }
+
+        assert_eq!(RedBlack::<usize, usize>::init().floor_entry(&1), None);
+        assert_eq!(RedBlack::<usize, usize>::init().ceiling_entry(&1), None);
+    }
+
+    #[test]
+    fn tree_rb_hash_1() {
+        fn hash_of<T: Hash>(item: &T) -> u64 {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            item.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut ascending = RedBlack::<usize, usize>::init();
+        let mut scattered = RedBlack::<usize, usize>::init();
+        for i in 0..100 {
+            ascending.insert(i, i * 10);
+            scattered.insert((i * 37) % 100, (i * 37) % 100 * 10);
+        }
+
+        assert!(ascending == scattered);
+        assert_eq!(hash_of(&ascending), hash_of(&scattered));
+
+        scattered.insert(50, 0);
+        assert!(ascending != scattered);
+        assert_ne!(hash_of(&ascending), hash_of(&scattered));
+
+        scattered.delete(&50);
+        assert!(ascending != scattered);
+
+        assert!(RedBlack::<usize, usize>::init() == RedBlack::<usize, usize>::init());
+        assert_eq!(
+            hash_of(&RedBlack::<usize, usize>::init()),
+            hash_of(&RedBlack::<usize, usize>::init())
+        );
+    }
+
+    #[test]
+    fn tree_rb_average_depth_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+        assert_eq!(rb_tree.average_depth(), 0.0);
+
+        rb_tree.insert(1, 10);
+        assert_eq!(rb_tree.average_depth(), 0.0);
+
+        // inserting 1..=7 in order results in a perfect tree rooted at 4
+        for i in 2..=7 {
+            rb_tree.insert(i, i * 10);
+        }
+        assert_eq!(
+            rb_tree.keys_in_level_order(),
+            vec![&4, &2, &6, &1, &3, &5, &7]
+        );
+
+        // depths are 0, 1, 1, 2, 2, 2, 2
+        assert!((rb_tree.average_depth() - 10.0 / 7.0).abs() < 1e-9);
+
+        for i in 8..1000 {
+            rb_tree.insert(i, i * 10);
+        }
+        assert!(rb_tree.average_depth() <= rb_tree.height() as f64);
+    }
+
+    #[test]
+    fn tree_rb_values_iter_1() {
+        let mut rb_tree: RedBlack<usize, usize> = RedBlack::init();
+        assert_eq!(rb_tree.values_iter().next(), None);
+
+        // later insertions overwrite values of existing keys
+        for i in 0..500 {
+            rb_tree.insert((i * 37) % 101, i);
+        }
+
+        assert_eq!(
+            rb_tree.values_iter().collect::<Vec<&usize>>(),
+            rb_tree.values()
+        );
+        assert_eq!(rb_tree.values_iter().count(), rb_tree.size());
+    }
+
+    #[test]
+    fn tree_rb_contains_key_1() {
+        let mut rb_tree: RedBlack<usize, usize> = RedBlack::init();
+        assert!(!rb_tree.contains_key(&0));
+
+        for i in (0..100).step_by(2) {
+            rb_tree.insert(i, i);
+        }
+
+        for i in 0..110 {
+            assert_eq!(rb_tree.contains_key(&i), rb_tree.contains(&i));
+            assert_eq!(rb_tree.contains_key(&i), i < 100 && i % 2 == 0);
+        }
+    }
+
+    #[test]
+    fn tree_rb_get_or_insert_1() {
+        let mut rb_tree: RedBlack<usize, usize> = RedBlack::init();
+
+        // get_or_insert finds or inserts the key in one descent and borrows the value by rank in a second one,
+        // so every insertion rebalances the tree between the two descents and the rank must still lead to `key`
+        for i in 0..200 {
+            let key = (i * 37) % 101;
+            let (existed, value) = rb_tree.get_or_insert(key, i);
+
+            // keys repeat every 101 insertions
+            assert_eq!(existed, i >= 101);
+            assert_eq!(*value, if existed { i - 101 + 1000 } else { i });
+
+            *value += 1000;
+            let expected = *value;
+            assert_eq!(*rb_tree.get(&key).unwrap(), expected);
+        }
+
+        assert_eq!(rb_tree.size(), 101);
+
+        assert!(is_23(&rb_tree.root, true));
+        assert!(black_height(&rb_tree.root).is_some());
+    }
+
+    #[test]
+    fn tree_rb_from_sorted_iter_1() {
+        let rb_tree = RedBlack::from_sorted_iter((0..1000).map(|i| (i, i * 10)));
+
+        assert_eq!(rb_tree.size(), 1000);
+        for i in 0..1000 {
+            assert_eq!(*rb_tree.get(&i).unwrap(), i * 10);
+        }
+
+        assert!(!Node::is_red(&rb_tree.root));
+        assert!(is_23(&rb_tree.root, true));
+        assert!(black_height(&rb_tree.root).is_some());
+        assert!(is_bst(&rb_tree.root, None, None));
+        assert!(is_size_consistent(&rb_tree.root));
+        assert!(is_rank_consistent(&rb_tree));
+
+        // every size must produce a valid tree
+        for size in 0..200 {
+            let mut rb_tree = RedBlack::from_sorted_iter((0..size).map(|i| (i, i)));
+
+            assert_eq!(rb_tree.size(), size);
+            assert!(!Node::is_red(&rb_tree.root));
+            assert!(is_23(&rb_tree.root, true));
+            assert!(black_height(&rb_tree.root).is_some());
+            assert!(is_bst(&rb_tree.root, None, None));
+            assert!(is_size_consistent(&rb_tree.root));
+
+            rb_tree.insert(size, size);
+            assert!(is_23(&rb_tree.root, true));
+            assert!(black_height(&rb_tree.root).is_some());
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Keys must be sorted in strictly ascending order")]
+    fn tree_rb_from_sorted_iter_panic() {
+        RedBlack::from_sorted_iter(vec![(2, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn tree_rb_pop_min_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+
+        for i in (0..100).rev() {
+            rb_tree.insert(i, i * 10);
+        }
+
+        for i in 0..100 {
+            assert_eq!(rb_tree.pop_min(), Some((i, i * 10)));
+            assert_eq!(rb_tree.size(), 99 - i);
+
+            assert!(is_23(&rb_tree.root, true));
+            assert!(is_bst(&rb_tree.root, None, None));
+            assert!(is_size_consistent(&rb_tree.root));
+            assert!(is_rank_consistent(&rb_tree));
+        }
+
+        assert_eq!(rb_tree.pop_min(), None);
+    }
+
+    #[test]
+    fn tree_rb_pop_max_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+
+        for i in 0..100 {
+            rb_tree.insert(i, i * 10);
+        }
+
+        for i in (0..100).rev() {
+            assert_eq!(rb_tree.pop_max(), Some((i, i * 10)));
+            assert_eq!(rb_tree.size(), i);
+
+            assert!(is_23(&rb_tree.root, true));
+            assert!(is_bst(&rb_tree.root, None, None));
+            assert!(is_size_consistent(&rb_tree.root));
+            assert!(is_rank_consistent(&rb_tree));
+        }
+
+        assert_eq!(rb_tree.pop_max(), None);
+    }
+
     #[test]
     fn tree_rb_floor_1() {
         let mut rb_tree = RedBlack::<usize, usize>::init();
@@ -1411,6 +2772,50 @@ mod tests {
         assert!(is_rank_consistent(&rb_tree));
     }
 
+    #[test]
+    fn tree_rb_values_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+
+        for i in (1..100).rev() {
+            rb_tree.insert(i, i * 10);
+        }
+
+        let keys = rb_tree.keys();
+        let values = rb_tree.values();
+
+        assert_eq!(keys.len(), values.len());
+        for (key, value) in keys.iter().zip(values.iter()) {
+            assert_eq!(**key * 10, **value);
+        }
+
+        assert!(is_23(&rb_tree.root, true));
+        assert!(is_bst(&rb_tree.root, None, None));
+        assert!(is_size_consistent(&rb_tree.root));
+        assert!(is_rank_consistent(&rb_tree));
+    }
+
+    #[test]
+    fn tree_rb_values_mut_1() {
+        let mut rb_tree = RedBlack::<usize, usize>::init();
+
+        for i in (1..100).rev() {
+            rb_tree.insert(i, i);
+        }
+
+        for value in rb_tree.values_mut() {
+            *value += 1;
+        }
+
+        for i in 1..100 {
+            assert_eq!(*rb_tree.get(&i).unwrap(), i + 1);
+        }
+
+        assert!(is_23(&rb_tree.root, true));
+        assert!(is_bst(&rb_tree.root, None, None));
+        assert!(is_size_consistent(&rb_tree.root));
+        assert!(is_rank_consistent(&rb_tree));
+    }
+
     #[test]
     fn tree_rb_keys_between_1() {
         let mut rb_tree = RedBlack::<usize, usize>::init();
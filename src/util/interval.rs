@@ -157,6 +157,38 @@ impl<T: Ord> Interval<T> {
         Rc::clone(&self.high)
     }
 
+    /// Returns true if interval is a single value closed at both sides: [x,x], false otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert!(Interval::point(2).is_point());
+    /// assert!(Interval::new(Included(2), Included(2)).is_point());
+    /// assert!(!Interval::new(Included(2), Excluded(3)).is_point());
+    /// ```
+    pub fn is_point(&self) -> bool {
+        match (self.low(), self.high()) {
+            (Included(low), Included(high)) => low == high,
+            _ => false,
+        }
+    }
+
+    /// Returns true if neither end of the interval is unbounded, false otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert!(Interval::new(Excluded(2), Included(4)).is_bounded());
+    /// assert!(!Interval::new(Included(2), Unbounded).is_bounded());
+    /// ```
+    pub fn is_bounded(&self) -> bool {
+        !matches!((self.low(), self.high()), (Unbounded, _) | (_, Unbounded))
+    }
+
     /// Returns true if `first` and `second` intervals overlap, false otherwise
     ///
     /// # Examples
@@ -283,135 +315,415 @@ impl<T: Ord> Interval<T> {
 
         Some(Interval { low, high })
     }
-}
 
-impl<T: Ord + std::fmt::Display> std::fmt::Display for Interval<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let low: String;
-        let high: String;
+    /// Returns the nearest value to `value` that lies within the interval:
+    /// the low end if `value` is below the interval, the high end if `value` is above the interval and `value` itself otherwise.
+    /// An unbounded end never clamps `value`.
+    ///
+    /// Note that the value of an end is returned regardless of the end being open or closed.
+    /// For example clamping 0 into (2,8] returns 2, even though 2 is not in the interval.
+    ///
+    /// # Arguments
+    /// * `value`: value to be clamped into the interval
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let interval = Interval::new(Included(2), Excluded(8));
+    ///
+    /// assert_eq!(interval.clamp_value(0), 2);
+    /// assert_eq!(interval.clamp_value(5), 5);
+    /// assert_eq!(interval.clamp_value(10), 8);
+    /// ```
+    pub fn clamp_value(&self, value: T) -> T
+    where
+        T: Clone,
+    {
+        match self.low() {
+            Included(low) | Excluded(low) if value < *low => return low.clone(),
+            _ => (),
+        }
 
-        low = match &self.low() {
-            Included(low) => format!("[{}", low),
-            Excluded(low) => format!("({}", low),
-            Unbounded => format!("(_"),
+        match self.high() {
+            Included(high) | Excluded(high) if value > *high => high.clone(),
+            _ => value,
+        }
+    }
+
+    /// Returns an iterator over the integer points contained in the interval, in ascending order.
+    /// For example [2,5) yields 2, 3 and 4
+    ///
+    /// # Panics
+    /// * panics if interval is unbounded at either side
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let interval = Interval::new(Included(2), Excluded(5));
+    ///
+    /// assert_eq!(interval.iter_points().collect::<Vec<usize>>(), vec![2, 3, 4]);
+    /// ```
+    pub fn iter_points(&self) -> impl Iterator<Item = T>
+    where
+        T: Clone,
+        std::ops::RangeInclusive<T>: DoubleEndedIterator<Item = T>,
+    {
+        let (low, low_excluded) = match self.low() {
+            Included(low) => (low.clone(), false),
+            Excluded(low) => (low.clone(), true),
+            Unbounded => panic!("Interval must be bounded to iterate over its points"),
         };
 
-        high = match &self.high() {
-            Included(high) => format!("{}]", high),
-            Excluded(high) => format!("{})", high),
-            Unbounded => format!("_)"),
+        let (high, high_excluded) = match self.high() {
+            Included(high) => (high.clone(), false),
+            Excluded(high) => (high.clone(), true),
+            Unbounded => panic!("Interval must be bounded to iterate over its points"),
         };
 
-        write!(f, "{},{}", low, high)
+        let mut points = low..=high;
+
+        // drop the open ends
+        if low_excluded {
+            points.next();
+        }
+        if high_excluded {
+            points.next_back();
+        }
+
+        points
     }
-}
 
-impl<T: Ord> PartialEq for Interval<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.low == other.low && self.high == other.high
+    /// Splits the interval at `value` into the lower part `[low, value)` and the higher part `[value, high]`.
+    /// Ends of the interval keep their original bounds in the pieces.
+    /// Returns `None` if `value` does not lie strictly between the ends of the interval
+    ///
+    /// # Arguments
+    /// * `value`: value to split the interval at
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let interval = Interval::new(Included(0), Excluded(10));
+    ///
+    /// let (lower, higher) = interval.split_at(&5).unwrap();
+    /// assert_eq!(format!("{}", lower), "[0,5)");
+    /// assert_eq!(format!("{}", higher), "[5,10)");
+    ///
+    /// assert!(interval.split_at(&0).is_none());
+    /// ```
+    pub fn split_at(&self, value: &T) -> Option<(Interval<T>, Interval<T>)>
+    where
+        T: Clone,
+    {
+        let above_low = match self.low() {
+            Included(low) | Excluded(low) => *value > *low,
+            Unbounded => true,
+        };
+
+        let below_high = match self.high() {
+            Included(high) | Excluded(high) => *value < *high,
+            Unbounded => true,
+        };
+
+        if !above_low || !below_high {
+            return None;
+        }
+
+        let lower = Interval {
+            low: Rc::clone(&self.low),
+            high: Rc::new(Excluded(value.clone())),
+        };
+        let higher = Interval {
+            low: Rc::new(Included(value.clone())),
+            high: Rc::clone(&self.high),
+        };
+
+        Some((lower, higher))
     }
-}
 
-impl<T: Ord> Eq for Interval<T> {}
+    /// Returns a new interval with both ends shifted by `delta`.
+    /// Ends keep being open or closed and unbounded ends stay unbounded
+    ///
+    /// # Arguments
+    /// * `delta`: offset to be added to the ends of the interval
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let interval = Interval::new(Included(2), Excluded(5));
+    ///
+    /// assert_eq!(format!("{}", interval.translate(3)), "[5,8)");
+    /// ```
+    pub fn translate(&self, delta: T) -> Interval<T>
+    where
+        T: std::ops::Add<Output = T> + Clone,
+    {
+        let shift = |bound: &Bound<T>| match bound {
+            Included(value) => Included(value.clone() + delta.clone()),
+            Excluded(value) => Excluded(value.clone() + delta.clone()),
+            Unbounded => Unbounded,
+        };
 
-impl<T: Ord> PartialOrd for Interval<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        // compare low end of the intervals
-        let mut result = match (&self.low(), &other.low()) {
-            (Included(low1), Included(low2)) => {
-                if low1 < low2 {
-                    Some(Ordering::Less)
-                } else if low1 == low2 {
-                    None
-                } else {
-                    Some(Ordering::Greater)
-                }
-            }
+        Interval::new(shift(self.low()), shift(self.high()))
+    }
+
+    /// Compares two intervals by their high end first and uses their low end to break ties.
+    /// Unlike the default ordering of intervals, which compares low ends first,
+    /// this orders intervals by the point they finish at
+    ///
+    /// # Arguments
+    /// * `other`: interval to compare to
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mut intervals = vec![
+    ///     Interval::new(Included(0), Included(6)),
+    ///     Interval::new(Included(1), Included(4)),
+    ///     Interval::new(Included(3), Excluded(4)),
+    /// ];
+    ///
+    /// intervals.sort_by(|first, second| first.cmp_by_high(second));
+    ///
+    /// assert_eq!(format!("{}", intervals[0]), "[3,4)");
+    /// assert_eq!(format!("{}", intervals[1]), "[1,4]");
+    /// assert_eq!(format!("{}", intervals[2]), "[0,6]");
+    /// ```
+    pub fn cmp_by_high(&self, other: &Interval<T>) -> Ordering {
+        Interval::cmp_high(self, other).then_with(|| Interval::cmp_low(self, other))
+    }
+
+    fn cmp_low(first: &Interval<T>, second: &Interval<T>) -> Ordering {
+        match (&first.low(), &second.low()) {
+            (Included(low1), Included(low2)) | (Excluded(low1), Excluded(low2)) => low1.cmp(low2),
             (Included(low1), Excluded(low2)) => {
                 if low1 <= low2 {
-                    Some(Ordering::Less)
+                    Ordering::Less
                 } else {
-                    Some(Ordering::Greater)
+                    Ordering::Greater
                 }
             }
             (Excluded(low1), Included(low2)) => {
                 if low1 < low2 {
-                    Some(Ordering::Less)
+                    Ordering::Less
                 } else {
-                    Some(Ordering::Greater)
+                    Ordering::Greater
                 }
             }
-            (Excluded(low1), Excluded(low2)) => {
-                if low1 < low2 {
-                    Some(Ordering::Less)
-                } else if low1 == low2 {
-                    None
+
+            (Unbounded, Included(_)) => Ordering::Less,
+            (Unbounded, Excluded(_)) => Ordering::Less,
+
+            (Included(_), Unbounded) => Ordering::Greater,
+            (Excluded(_), Unbounded) => Ordering::Greater,
+
+            (Unbounded, Unbounded) => Ordering::Equal,
+        }
+    }
+
+    fn cmp_high(first: &Interval<T>, second: &Interval<T>) -> Ordering {
+        match (&first.high(), &second.high()) {
+            (Included(high1), Included(high2)) | (Excluded(high1), Excluded(high2)) => {
+                high1.cmp(high2)
+            }
+            (Included(high1), Excluded(high2)) => {
+                if high1 < high2 {
+                    Ordering::Less
                 } else {
-                    Some(Ordering::Greater)
+                    Ordering::Greater
                 }
             }
+            (Excluded(high1), Included(high2)) => {
+                if high1 <= high2 {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+            (Unbounded, Included(_)) => Ordering::Greater,
+            (Unbounded, Excluded(_)) => Ordering::Greater,
 
-            (Unbounded, Included(_)) => Some(Ordering::Less),
-            (Unbounded, Excluded(_)) => Some(Ordering::Less),
+            (Included(_), Unbounded) => Ordering::Less,
+            (Excluded(_), Unbounded) => Ordering::Less,
 
-            (Included(_), Unbounded) => Some(Ordering::Greater),
-            (Excluded(_), Unbounded) => Some(Ordering::Greater),
+            (Unbounded, Unbounded) => Ordering::Equal,
+        }
+    }
 
-            (Unbounded, Unbounded) => None,
+    /// Returns true if `first` and `second` do not overlap but there is no gap between them, false otherwise.
+    /// ex. [1,3) and [3,5] are adjacent but [1,3) and (3,5] are not
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let interval1 = Interval::new(Included(1), Excluded(3));
+    /// let interval2 = Interval::new(Included(3), Included(5));
+    /// let interval3 = Interval::new(Excluded(3), Included(5));
+    ///
+    /// assert!(Interval::adjacent(&interval1, &interval2));
+    /// assert!(!Interval::adjacent(&interval1, &interval3));
+    /// ```
+    pub fn adjacent(first: &Interval<T>, second: &Interval<T>) -> bool {
+        let (lower, higher) = if first <= second {
+            (first, second)
+        } else {
+            (second, first)
         };
 
-        // if low end was not enough to determine ordering, use high end
-        if result.is_none() {
-            result = match (&self.high(), &other.high()) {
-                (Included(high1), Included(high2)) => {
-                    if high1 < high2 {
-                        Some(Ordering::Less)
-                    } else if high1 == high2 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Greater)
-                    }
-                }
-                (Included(high1), Excluded(high2)) => {
-                    if high1 < high2 {
-                        Some(Ordering::Less)
-                    } else {
-                        Some(Ordering::Greater)
-                    }
-                }
-                (Excluded(high1), Included(high2)) => {
-                    if high1 <= high2 {
-                        Some(Ordering::Less)
-                    } else {
-                        Some(Ordering::Greater)
-                    }
+        match (lower.high(), higher.low()) {
+            (Included(high), Excluded(low)) | (Excluded(high), Included(low)) => high == low,
+            _ => false,
+        }
+    }
+
+    /// Get union of `first` and `second` if they overlap or are adjacent, `None` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let interval1 = Interval::new(Included(1), Included(3));
+    /// let interval2 = Interval::new(Included(2), Excluded(5));
+    /// let interval3 = Interval::new(Included(5), Included(8));
+    ///
+    /// assert!(Interval::get_union(&interval1, &interval2).unwrap() == Interval::new(Included(1), Excluded(5)));
+    /// assert!(Interval::get_union(&interval2, &interval3).unwrap() == Interval::new(Included(2), Included(8)));
+    /// assert!(Interval::get_union(&interval1, &interval3).is_none());
+    /// ```
+    pub fn get_union(first: &Interval<T>, second: &Interval<T>) -> Option<Interval<T>> {
+        if !Interval::overlaps(first, second) && !Interval::adjacent(first, second) {
+            return None;
+        }
+
+        // lower interval in the ordering has the lowest low bound
+        let low = if first <= second {
+            Rc::clone(&first.low)
+        } else {
+            Rc::clone(&second.low)
+        };
+
+        let high = match (&first.high(), &second.high()) {
+            (Included(high1), Included(high2))
+            | (Excluded(high1), Included(high2))
+            | (Excluded(high1), Excluded(high2)) => {
+                if high1 > high2 {
+                    Rc::clone(&first.high)
+                } else {
+                    Rc::clone(&second.high)
                 }
-                (Excluded(high1), Excluded(high2)) => {
-                    if high1 < high2 {
-                        Some(Ordering::Less)
-                    } else if high1 == high2 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Greater)
-                    }
+            }
+            (Included(high1), Excluded(high2)) => {
+                if high1 >= high2 {
+                    Rc::clone(&first.high)
+                } else {
+                    Rc::clone(&second.high)
                 }
-                (Unbounded, Included(_)) => Some(Ordering::Greater),
-                (Unbounded, Excluded(_)) => Some(Ordering::Greater),
+            }
+            (Unbounded, _) => Rc::clone(&first.high),
+            (_, Unbounded) => Rc::clone(&second.high),
+        };
 
-                (Included(_), Unbounded) => Some(Ordering::Less),
-                (Excluded(_), Unbounded) => Some(Ordering::Less),
+        Some(Interval { low, high })
+    }
 
-                (Unbounded, Unbounded) => Some(Ordering::Equal),
+    /// Sorts `intervals` and merges every overlapping or adjacent intervals.
+    /// Returned intervals are disjoint, non-adjacent and sorted.
+    ///
+    /// # Arguments
+    /// * `intervals`: intervals to be normalized
+    ///
+    /// # Examples
+    /// ```
+    /// use rudac::util::Interval;
+    /// use std::ops::Bound::*;
+    ///
+    /// let intervals = vec![
+    ///     Interval::new(Included(5), Excluded(6)),
+    ///     Interval::new(Included(1), Included(3)),
+    ///     Interval::new(Included(6), Included(8)),
+    ///     Interval::new(Included(2), Included(4)),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Interval::normalize(intervals),
+    ///     vec![
+    ///         Interval::new(Included(1), Included(4)),
+    ///         Interval::new(Included(5), Included(8)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn normalize(mut intervals: Vec<Interval<T>>) -> Vec<Interval<T>> {
+        intervals.sort();
+
+        let mut normalized: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            let union = match normalized.last() {
+                Some(last) => Interval::get_union(last, &interval),
+                None => None,
             };
+
+            match union {
+                Some(union) => *normalized.last_mut().unwrap() = union,
+                None => normalized.push(interval),
+            }
         }
 
-        return result;
+        normalized
+    }
+}
+
+impl<T: Ord + std::fmt::Display> std::fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let low: String;
+        let high: String;
+
+        low = match &self.low() {
+            Included(low) => format!("[{}", low),
+            Excluded(low) => format!("({}", low),
+            Unbounded => format!("(_"),
+        };
+
+        high = match &self.high() {
+            Included(high) => format!("{}]", high),
+            Excluded(high) => format!("{})", high),
+            Unbounded => format!("_)"),
+        };
+
+        write!(f, "{},{}", low, high)
+    }
+}
+
+impl<T: Ord> PartialEq for Interval<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.low == other.low && self.high == other.high
+    }
+}
+
+impl<T: Ord> Eq for Interval<T> {}
+
+impl<T: Ord> PartialOrd for Interval<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl<T: Ord> Ord for Interval<T> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        // compare low end of the intervals and if it was not enough to determine ordering, use high end
+        Interval::cmp_low(self, other).then_with(|| Interval::cmp_high(self, other))
     }
 }
 
@@ -698,4 +1010,260 @@ mod tests {
 
         assert!(Interval::contains(&interval1, &interval2));
     }
+
+    #[test]
+    fn util_interval_adjacent_1() {
+        let interval1 = Interval::new(Included(1), Included(3));
+        let interval2 = Interval::new(Excluded(3), Included(5));
+        let interval3 = Interval::new(Included(3), Included(5));
+        let interval4 = Interval::new(Excluded(3), Excluded(5));
+        let interval5 = Interval::new(Excluded(1), Excluded(3));
+
+        assert!(Interval::adjacent(&interval1, &interval2));
+        assert!(Interval::adjacent(&interval2, &interval1));
+        assert!(!Interval::adjacent(&interval1, &interval3));
+        assert!(!Interval::adjacent(&interval5, &interval4));
+    }
+
+    #[test]
+    fn util_interval_get_union_1() {
+        let interval1 = Interval::new(Included(1), Included(3));
+        let interval2 = Interval::new(Included(2), Included(5));
+        let interval3 = Interval::new(Excluded(5), Unbounded);
+        let interval4 = Interval::new(Included(7), Included(8));
+
+        assert!(
+            Interval::get_union(&interval1, &interval2).unwrap()
+                == Interval::new(Included(1), Included(5))
+        );
+        assert!(
+            Interval::get_union(&interval2, &interval1).unwrap()
+                == Interval::new(Included(1), Included(5))
+        );
+        assert!(
+            Interval::get_union(&interval2, &interval3).unwrap()
+                == Interval::new(Included(2), Unbounded)
+        );
+        assert!(
+            Interval::get_union(&interval3, &interval4).unwrap()
+                == Interval::new(Excluded(5), Unbounded)
+        );
+        assert!(Interval::get_union(&interval1, &interval4).is_none());
+    }
+
+    #[test]
+    fn util_interval_cmp_by_high_1() {
+        let mut intervals = vec![
+            Interval::new(Included(1), Unbounded),
+            Interval::new(Included(0), Included(6)),
+            Interval::new(Included(5), Excluded(7)),
+            Interval::new(Included(3), Included(5)),
+            Interval::new(Excluded(3), Included(5)),
+            Interval::new(Unbounded, Excluded(5)),
+            Interval::new(Included(1), Included(4)),
+        ];
+
+        intervals.sort_by(|first, second| first.cmp_by_high(second));
+
+        let accept = String::from("[1,4](_,5)[3,5](3,5][0,6][5,7)[1,_)");
+
+        let mut result = String::from("");
+        for interval in intervals {
+            result.push_str(&format!("{}", interval))
+        }
+
+        assert_eq!(result, accept);
+    }
+
+    #[test]
+    fn util_interval_clamp_value_1() {
+        let interval = Interval::new(Included(2), Included(8));
+
+        assert_eq!(interval.clamp_value(0), 2);
+        assert_eq!(interval.clamp_value(1), 2);
+        assert_eq!(interval.clamp_value(2), 2);
+        assert_eq!(interval.clamp_value(5), 5);
+        assert_eq!(interval.clamp_value(8), 8);
+        assert_eq!(interval.clamp_value(9), 8);
+        assert_eq!(interval.clamp_value(100), 8);
+    }
+
+    #[test]
+    fn util_interval_clamp_value_2() {
+        let interval = Interval::new(Excluded(2), Excluded(8));
+        assert_eq!(interval.clamp_value(0), 2);
+        assert_eq!(interval.clamp_value(10), 8);
+
+        let interval = Interval::new(Unbounded, Included(8));
+        assert_eq!(interval.clamp_value(0), 0);
+        assert_eq!(interval.clamp_value(10), 8);
+
+        let interval = Interval::<usize>::new(Unbounded, Unbounded);
+        assert_eq!(interval.clamp_value(0), 0);
+        assert_eq!(interval.clamp_value(10), 10);
+    }
+
+    #[test]
+    fn util_interval_iter_points_1() {
+        let interval = Interval::new(Included(2), Included(5));
+        assert_eq!(
+            interval.iter_points().collect::<Vec<i32>>(),
+            vec![2, 3, 4, 5]
+        );
+
+        let interval = Interval::new(Included(2), Excluded(5));
+        assert_eq!(interval.iter_points().collect::<Vec<i32>>(), vec![2, 3, 4]);
+
+        let interval = Interval::new(Excluded(2), Excluded(5));
+        assert_eq!(interval.iter_points().collect::<Vec<i32>>(), vec![3, 4]);
+
+        let interval = Interval::new(Excluded(2), Excluded(3));
+        assert_eq!(interval.iter_points().count(), 0);
+
+        let interval = Interval::point(-7);
+        assert_eq!(interval.iter_points().collect::<Vec<i32>>(), vec![-7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Interval must be bounded to iterate over its points")]
+    fn util_interval_panic_iter_points() {
+        let interval = Interval::new(Included(2), Unbounded);
+
+        interval.iter_points().for_each(drop);
+    }
+
+    #[test]
+    fn util_interval_split_at_1() {
+        let interval = Interval::new(Included(0), Included(10));
+
+        let (lower, higher) = interval.split_at(&5).unwrap();
+        assert!(lower == Interval::new(Included(0), Excluded(5)));
+        assert!(higher == Interval::new(Included(5), Included(10)));
+
+        assert!(interval.split_at(&0).is_none());
+        assert!(interval.split_at(&10).is_none());
+        assert!(interval.split_at(&11).is_none());
+        assert!(interval.split_at(&-1).is_none());
+    }
+
+    #[test]
+    fn util_interval_split_at_2() {
+        let interval = Interval::new(Excluded(0), Unbounded);
+
+        let (lower, higher) = interval.split_at(&1).unwrap();
+        assert_eq!(format!("{}", lower), "(0,1)");
+        assert_eq!(format!("{}", higher), "[1,_)");
+
+        let interval = Interval::<i32>::new(Unbounded, Unbounded);
+        let (lower, higher) = interval.split_at(&1).unwrap();
+        assert_eq!(format!("{}", lower), "(_,1)");
+        assert_eq!(format!("{}", higher), "[1,_)");
+
+        assert!(Interval::point(3).split_at(&3).is_none());
+    }
+
+    #[test]
+    fn util_interval_translate_1() {
+        let interval = Interval::new(Included(2), Excluded(5));
+        assert!(interval.translate(3) == Interval::new(Included(5), Excluded(8)));
+        assert!(interval.translate(-4) == Interval::new(Included(-2), Excluded(1)));
+        assert!(interval.translate(0) == interval);
+
+        let interval = Interval::new(Excluded(2), Unbounded);
+        assert!(interval.translate(3) == Interval::new(Excluded(5), Unbounded));
+
+        let interval = Interval::<i32>::new(Unbounded, Unbounded);
+        assert!(interval.translate(3) == interval);
+
+        let point = Interval::point(7);
+        assert!(point.translate(10) == Interval::point(17));
+    }
+
+    #[test]
+    fn util_interval_is_point_and_is_bounded() {
+        let point = Interval::point(3);
+        assert!(point.is_point());
+        assert!(point.is_bounded());
+
+        let closed = Interval::new(Included(3), Included(3));
+        assert!(closed.is_point());
+        assert!(closed.is_bounded());
+
+        let bounded = [
+            Interval::new(Included(1), Included(5)),
+            Interval::new(Included(1), Excluded(5)),
+            Interval::new(Excluded(1), Included(5)),
+            Interval::new(Excluded(1), Excluded(2)),
+        ];
+        for interval in bounded.iter() {
+            assert!(!interval.is_point());
+            assert!(interval.is_bounded());
+        }
+
+        let unbounded = [
+            Interval::new(Unbounded, Included(5)),
+            Interval::new(Excluded(1), Unbounded),
+            Interval::new(Unbounded, Unbounded),
+        ];
+        for interval in unbounded.iter() {
+            assert!(!interval.is_point());
+            assert!(!interval.is_bounded());
+        }
+    }
+
+    #[test]
+    fn util_interval_normalize_1() {
+        let intervals = vec![
+            Interval::new(Included(6), Included(8)),
+            Interval::new(Included(2), Included(4)),
+            Interval::new(Included(5), Included(6)),
+            Interval::new(Included(1), Included(3)),
+        ];
+
+        assert_eq!(
+            Interval::normalize(intervals),
+            vec![
+                Interval::new(Included(1), Included(4)),
+                Interval::new(Included(5), Included(8)),
+            ]
+        );
+
+        let intervals = vec![
+            Interval::new(Unbounded, Excluded(0)),
+            Interval::new(Excluded(10), Unbounded),
+            Interval::new(Excluded(0), Included(1)),
+            Interval::new(Included(1), Excluded(10)),
+            Interval::point(10),
+        ];
+
+        assert_eq!(
+            Interval::normalize(intervals),
+            vec![
+                Interval::new(Unbounded, Excluded(0)),
+                Interval::new(Excluded(0), Unbounded),
+            ]
+        );
+
+        assert!(Interval::<usize>::normalize(vec![]).is_empty());
+    }
+
+    #[test]
+    fn util_interval_normalize_disjoint() {
+        let intervals = vec![
+            Interval::new(Included(7), Included(9)),
+            Interval::point(5),
+            Interval::new(Excluded(1), Excluded(3)),
+            Interval::new(Unbounded, Included(0)),
+        ];
+
+        assert_eq!(
+            Interval::normalize(intervals),
+            vec![
+                Interval::new(Unbounded, Included(0)),
+                Interval::new(Excluded(1), Excluded(3)),
+                Interval::point(5),
+                Interval::new(Included(7), Included(9)),
+            ]
+        );
+    }
 }